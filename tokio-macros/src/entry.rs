@@ -0,0 +1,588 @@
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{quote, quote_spanned};
+
+// syn::AttributeArgs does not implement syn::Parse
+type AttributeArgs = syn::punctuated::Punctuated<syn::Meta, syn::Token![,]>;
+
+#[derive(Clone, Copy, PartialEq)]
+enum RuntimeFlavor {
+    CurrentThread,
+    Threaded,
+}
+
+impl RuntimeFlavor {
+    fn from_str(s: &str) -> Result<RuntimeFlavor, String> {
+        match s {
+            "current_thread" => Ok(RuntimeFlavor::CurrentThread),
+            "multi_thread" => Ok(RuntimeFlavor::Threaded),
+            "single_thread" => Err("The single threaded runtime flavor is called \"current_thread\".".to_string()),
+            "basic_scheduler" => Err("The \"basic_scheduler\" runtime flavor has been renamed to \"current_thread\".".to_string()),
+            "threaded_scheduler" => Err("The \"threaded_scheduler\" runtime flavor has been renamed to \"multi_thread\".".to_string()),
+            _ => Err(format!("No such runtime flavor `{}`. The runtime flavors are `current_thread` and `multi_thread`.", s)),
+        }
+    }
+
+    fn ident(self) -> &'static str {
+        match self {
+            RuntimeFlavor::CurrentThread => "current_thread",
+            RuntimeFlavor::Threaded => "multi_thread",
+        }
+    }
+}
+
+struct FinalConfig {
+    flavors: Vec<RuntimeFlavor>,
+    worker_threads: Option<usize>,
+    start_paused: Option<bool>,
+    crate_name: Option<String>,
+    deterministic_seed: Option<u64>,
+}
+
+struct Configuration {
+    rt_multi_thread_available: bool,
+    default_flavor: RuntimeFlavor,
+    flavors: Option<Vec<RuntimeFlavor>>,
+    worker_threads: Option<(usize, Span)>,
+    start_paused: Option<(bool, Span)>,
+    is_test: bool,
+    crate_name: Option<String>,
+    deterministic: bool,
+    seed: Option<(u64, Span)>,
+}
+
+impl Configuration {
+    fn new(is_test: bool, rt_multi_thread: bool) -> Self {
+        Configuration {
+            rt_multi_thread_available: rt_multi_thread,
+            // Upstream Tokio defaults `#[tokio::main]` to the multi-threaded
+            // scheduler; this crate only implements `current_thread`, so
+            // that's the default regardless of `is_test` until a real
+            // multi-threaded scheduler lands.
+            default_flavor: RuntimeFlavor::CurrentThread,
+            flavors: None,
+            worker_threads: None,
+            start_paused: None,
+            is_test,
+            crate_name: None,
+            deterministic: false,
+            seed: None,
+        }
+    }
+
+    fn set_deterministic(&mut self, span: Span) -> Result<(), syn::Error> {
+        if !self.is_test {
+            return Err(syn::Error::new(span, "`deterministic` is only supported on `#[tokio::test]`."));
+        }
+        self.deterministic = true;
+        Ok(())
+    }
+
+    fn set_seed(&mut self, seed: syn::Lit, span: Span) -> Result<(), syn::Error> {
+        if self.seed.is_some() {
+            return Err(syn::Error::new(span, "`seed` set multiple times."));
+        }
+        if !self.is_test {
+            return Err(syn::Error::new(span, "`seed` is only supported on `#[tokio::test]`."));
+        }
+        let seed = parse_int(seed, span, "seed")? as u64;
+        self.seed = Some((seed, span));
+        Ok(())
+    }
+
+    fn set_flavors(&mut self, runtime: syn::Lit, span: Span) -> Result<(), syn::Error> {
+        if self.flavors.is_some() {
+            return Err(syn::Error::new(span, "`flavor` set multiple times."));
+        }
+
+        let runtime_str = parse_string(runtime, span, "flavor")?;
+        if runtime_str == "all" {
+            self.flavors = Some(vec![RuntimeFlavor::CurrentThread, RuntimeFlavor::Threaded]);
+            return Ok(());
+        }
+
+        let runtime = RuntimeFlavor::from_str(&runtime_str).map_err(|err| syn::Error::new(span, err))?;
+        self.flavors = Some(vec![runtime]);
+        Ok(())
+    }
+
+    fn set_flavor_list(&mut self, list: &syn::MetaList, span: Span) -> Result<(), syn::Error> {
+        if self.flavors.is_some() {
+            return Err(syn::Error::new(span, "`flavors` set multiple times."));
+        }
+
+        let mut flavors = Vec::new();
+        for nested in &list.nested {
+            if let syn::NestedMeta::Lit(syn::Lit::Str(s)) = nested {
+                flavors.push(RuntimeFlavor::from_str(&s.value()).map_err(|err| syn::Error::new(span, err))?);
+            } else {
+                return Err(syn::Error::new(span, "`flavors` expects a list of string literals, e.g. `flavors(\"current_thread\", \"multi_thread\")`."));
+            }
+        }
+
+        if flavors.is_empty() {
+            return Err(syn::Error::new(span, "`flavors` must list at least one runtime flavor."));
+        }
+
+        self.flavors = Some(flavors);
+        Ok(())
+    }
+
+    fn set_worker_threads(&mut self, worker_threads: syn::Lit, span: Span) -> Result<(), syn::Error> {
+        if self.worker_threads.is_some() {
+            return Err(syn::Error::new(span, "`worker_threads` set multiple times."));
+        }
+
+        let worker_threads = parse_int(worker_threads, span, "worker_threads")?;
+        if worker_threads == 0 {
+            return Err(syn::Error::new(span, "`worker_threads` may not be 0."));
+        }
+        self.worker_threads = Some((worker_threads, span));
+        Ok(())
+    }
+
+    fn set_start_paused(&mut self, start_paused: syn::Lit, span: Span) -> Result<(), syn::Error> {
+        if self.start_paused.is_some() {
+            return Err(syn::Error::new(span, "`start_paused` set multiple times."));
+        }
+
+        let start_paused = parse_bool(start_paused, span, "start_paused")?;
+        self.start_paused = Some((start_paused, span));
+        Ok(())
+    }
+
+    fn set_crate_name(&mut self, name: syn::Lit, span: Span) -> Result<(), syn::Error> {
+        if self.crate_name.is_some() {
+            return Err(syn::Error::new(span, "`crate` set multiple times."));
+        }
+        let name_ident = parse_string(name, span, "crate")?;
+        self.crate_name = Some(name_ident);
+        Ok(())
+    }
+
+    fn build(&self) -> Result<Vec<FinalConfig>, syn::Error> {
+        if self.deterministic && self.flavors.is_some() {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "`deterministic` always runs on the current-thread scheduler and cannot be combined with `flavor`/`flavors`.",
+            ));
+        }
+        if self.seed.is_some() && !self.deterministic {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "`seed` has no effect without `deterministic`.",
+            ));
+        }
+
+        let flavors = if self.deterministic {
+            vec![RuntimeFlavor::CurrentThread]
+        } else {
+            self.flavors.clone().unwrap_or_else(|| vec![self.default_flavor])
+        };
+
+        for flavor in &flavors {
+            if matches!(flavor, RuntimeFlavor::Threaded) {
+                return Err(syn::Error::new(
+                    Span::call_site(),
+                    "The \"multi_thread\" runtime flavor is not implemented yet; use \"current_thread\" (the default) instead.",
+                ));
+            }
+        }
+
+        // `flavors(...)` and `flavor = "all"` produce one generated function per flavor, each with
+        // an identical configuration apart from the scheduler itself.
+        Ok(flavors
+            .into_iter()
+            .map(|flavor| FinalConfig {
+                flavors: vec![flavor],
+                worker_threads: self.worker_threads.map(|(v, _)| v),
+                start_paused: self.start_paused.map(|(v, _)| v),
+                crate_name: self.crate_name.clone(),
+                deterministic_seed: if self.deterministic {
+                    Some(self.seed.map(|(v, _)| v).unwrap_or_else(|| {
+                        // No `seed` given: pick a fresh one per expansion. The
+                        // generated panic hook prints it as `TOKIO_TEST_SEED`.
+                        use std::hash::{BuildHasher, Hasher};
+                        std::collections::hash_map::RandomState::new().build_hasher().finish()
+                    }))
+                } else {
+                    None
+                },
+            })
+            .collect())
+    }
+}
+
+fn parse_int(int: syn::Lit, span: Span, field: &str) -> Result<usize, syn::Error> {
+    match int {
+        syn::Lit::Int(lit) => lit.base10_parse::<usize>().map_err(|_| syn::Error::new(span, format!("Failed to parse value of `{}` as integer.", field))),
+        _ => Err(syn::Error::new(span, format!("Failed to parse value of `{}` as integer.", field))),
+    }
+}
+
+fn parse_string(int: syn::Lit, span: Span, field: &str) -> Result<String, syn::Error> {
+    match int {
+        syn::Lit::Str(s) => Ok(s.value()),
+        syn::Lit::Verbatim(s) => Ok(s.to_string()),
+        _ => Err(syn::Error::new(span, format!("Failed to parse value of `{}` as string.", field))),
+    }
+}
+
+fn parse_bool(bool: syn::Lit, span: Span, field: &str) -> Result<bool, syn::Error> {
+    match bool {
+        syn::Lit::Bool(b) => Ok(b.value),
+        _ => Err(syn::Error::new(span, format!("Failed to parse value of `{}` as bool.", field))),
+    }
+}
+
+fn build_config(
+    input: syn::ItemFn,
+    args: AttributeArgs,
+    is_test: bool,
+    rt_multi_thread: bool,
+) -> Result<Vec<FinalConfig>, syn::Error> {
+    if input.sig.asyncness.is_none() {
+        let msg = "the `async` keyword is missing from the function declaration";
+        return Err(syn::Error::new_spanned(input.sig.fn_token, msg));
+    }
+
+    let mut config = Configuration::new(is_test, rt_multi_thread);
+
+    for arg in args {
+        match arg {
+            syn::Meta::NameValue(namevalue) => {
+                let ident = namevalue
+                    .path
+                    .get_ident()
+                    .ok_or_else(|| syn::Error::new_spanned(&namevalue, "Must have specified ident"))?
+                    .to_string()
+                    .to_lowercase();
+                let lit = &namevalue.lit;
+                let span = lit.span();
+                match ident.as_str() {
+                    "worker_threads" => config.set_worker_threads(lit.clone(), span)?,
+                    "flavor" => config.set_flavors(lit.clone(), span)?,
+                    "start_paused" => config.set_start_paused(lit.clone(), span)?,
+                    "crate" => config.set_crate_name(lit.clone(), span)?,
+                    "seed" => config.set_seed(lit.clone(), span)?,
+                    name => {
+                        let msg = format!("Unknown attribute {} is specified; expected one of: `flavor`, `flavors`, `worker_threads`, `start_paused`, `crate`, `deterministic`, `seed`", name);
+                        return Err(syn::Error::new_spanned(&namevalue, msg));
+                    }
+                }
+            }
+            syn::Meta::List(list) if list.path.is_ident("flavors") => {
+                config.set_flavor_list(&list, list.span())?;
+            }
+            syn::Meta::Path(path) if path.is_ident("deterministic") => {
+                config.set_deterministic(path.span())?;
+            }
+            other => {
+                return Err(syn::Error::new_spanned(other, "Unknown attribute inside the macro"));
+            }
+        }
+    }
+
+    config.build()
+}
+
+fn parse_knobs(input: syn::ItemFn, is_test: bool, configs: Vec<FinalConfig>) -> TokenStream {
+    if configs.len() == 1 {
+        return parse_knobs_single(input, is_test, &configs[0], None);
+    }
+
+    // Multiple flavors were requested: nest one copy of the function per
+    // flavor, named after the flavor, inside a module named after the
+    // original function, so the test harness reports e.g.
+    // `it_works::current_thread` and `it_works::multi_thread` as distinct
+    // tests rather than a single flat, underscore-joined name.
+    let mod_name = input.sig.ident.clone();
+    let mut variants = proc_macro2::TokenStream::new();
+    for config in &configs {
+        let flavor_name = config.flavors[0].ident();
+        let expanded = parse_knobs_single(input.clone(), is_test, config, Some(flavor_name));
+        variants.extend(proc_macro2::TokenStream::from(expanded));
+    }
+
+    quote! {
+        mod #mod_name {
+            use super::*;
+
+            #variants
+        }
+    }
+    .into()
+}
+
+fn parse_knobs_single(
+    mut input: syn::ItemFn,
+    is_test: bool,
+    config: &FinalConfig,
+    fn_name: Option<&str>,
+) -> TokenStream {
+    input.sig.asyncness = None;
+
+    if let Some(fn_name) = fn_name {
+        input.sig.ident = syn::Ident::new(fn_name, input.sig.ident.span());
+    }
+
+    let crate_name = config
+        .crate_name
+        .clone()
+        .map(|name| syn::Ident::new(&name, Span::call_site()))
+        .unwrap_or_else(|| syn::Ident::new("tokio", Span::call_site()));
+
+    let mut rt = match config.flavors[0] {
+        RuntimeFlavor::CurrentThread => quote! { #crate_name::runtime::Builder::new_current_thread() },
+        RuntimeFlavor::Threaded => quote! { #crate_name::runtime::Builder::new_multi_thread() },
+    };
+
+    if let Some(v) = config.worker_threads {
+        rt = quote! { { let mut rt = #rt; rt.worker_threads(#v); rt } };
+    }
+    if let Some(true) = config.start_paused {
+        rt = quote! { { let mut rt = #rt; rt.enable_all(); rt.start_paused(true); rt } };
+    }
+
+    let body = &input.block;
+    let attrs = &input.attrs;
+    let vis = &input.vis;
+    let sig = &mut input.sig;
+    let body_ident = quote! { body };
+    let header = if is_test {
+        quote! { #[::core::prelude::v1::test] }
+    } else {
+        quote! {}
+    };
+
+    // `deterministic` additionally seeds the scheduler's ready-queue PRNG
+    // and installs a panic hook that prints the seed on failure, so a
+    // flaky interleaving can be reproduced by re-running with the printed
+    // `TOKIO_TEST_SEED`.
+    let block_on_call = if let Some(seed) = config.deterministic_seed {
+        rt = quote! { { let mut rt = #rt; rt.enable_all(); rt.deterministic_seed(#seed); rt } };
+        quote! {
+            let seed: u64 = #seed;
+            let previous_hook = ::std::panic::take_hook();
+            ::std::panic::set_hook(::std::boxed::Box::new(move |info| {
+                eprintln!("TOKIO_TEST_SEED={}", seed);
+                previous_hook(info);
+            }));
+
+            #crate_name::runtime::Builder::enable_all(&mut #rt)
+                .build()
+                .expect("Failed building the Runtime")
+                .block_on(#body_ident)
+        }
+    } else {
+        quote! {
+            #crate_name::runtime::Builder::enable_all(&mut #rt)
+                .build()
+                .expect("Failed building the Runtime")
+                .block_on(#body_ident)
+        }
+    };
+
+    let result = quote_spanned! {input.span()=>
+        #header
+        #(#attrs)*
+        #vis #sig {
+            let #body_ident = async #body;
+
+            #block_on_call
+        }
+    };
+
+    result.into()
+}
+
+/// Parses the `crate = "..."` rename out of `args`, rejecting anything else
+/// (there's no builder to configure a flavor, worker count, or
+/// deterministic seed on under `rt-minimal`).
+fn parse_minimal_args(args: TokenStream) -> Result<Option<String>, syn::Error> {
+    let args = syn::parse::<AttributeArgsWrapper>(args)?.0;
+
+    let mut crate_name = None;
+    for arg in args {
+        match arg {
+            syn::Meta::NameValue(namevalue) if namevalue.path.is_ident("crate") => {
+                if crate_name.is_some() {
+                    return Err(syn::Error::new_spanned(&namevalue, "`crate` set multiple times."));
+                }
+                crate_name = Some(parse_string(namevalue.lit.clone(), namevalue.lit.span(), "crate")?);
+            }
+            other => {
+                let msg = "Unknown attribute is specified; under `rt-minimal` only `crate` is supported (there is no builder to configure a flavor, worker count, or deterministic seed on).";
+                return Err(syn::Error::new_spanned(other, msg));
+            }
+        }
+    }
+
+    Ok(crate_name)
+}
+
+/// Expands `#[tokio::main]`/`#[tokio::test]` when only the `rt-minimal`
+/// feature is enabled: no flavors, no worker threads, no builder — just a
+/// call into `#crate_name::rt_minimal::block_on`.
+pub(crate) fn minimal(args: TokenStream, item: TokenStream, is_test: bool) -> TokenStream {
+    let crate_name = match parse_minimal_args(args) {
+        Ok(it) => it,
+        Err(e) => return token_stream_with_error(item, e),
+    };
+    let crate_name = crate_name
+        .map(|name| syn::Ident::new(&name, Span::call_site()))
+        .unwrap_or_else(|| syn::Ident::new("tokio", Span::call_site()));
+
+    let mut input = match syn::parse::<syn::ItemFn>(item.clone()) {
+        Ok(it) => it,
+        Err(e) => return token_stream_with_error(item, e),
+    };
+
+    if input.sig.asyncness.is_none() {
+        let msg = "the `async` keyword is missing from the function declaration";
+        return token_stream_with_error(item, syn::Error::new_spanned(input.sig.fn_token, msg));
+    }
+    input.sig.asyncness = None;
+
+    let body = &input.block;
+    let attrs = &input.attrs;
+    let vis = &input.vis;
+    let sig = &mut input.sig;
+    let header = if is_test {
+        quote! { #[::core::prelude::v1::test] }
+    } else {
+        quote! {}
+    };
+
+    let result = quote_spanned! {input.span()=>
+        #header
+        #(#attrs)*
+        #vis #sig {
+            let body = async #body;
+            #crate_name::rt_minimal::block_on(body)
+        }
+    };
+
+    result.into()
+}
+
+pub(crate) fn main(args: TokenStream, item: TokenStream, rt_multi_thread: bool) -> TokenStream {
+    let input = match syn::parse::<syn::ItemFn>(item.clone()) {
+        Ok(it) => it,
+        Err(e) => return token_stream_with_error(item, e),
+    };
+    let args = match syn::parse::<AttributeArgsWrapper>(args) {
+        Ok(it) => it.0,
+        Err(e) => return token_stream_with_error(item, e),
+    };
+
+    // `#[tokio::main]` always builds exactly one runtime; `flavors(...)` is
+    // only meaningful for `#[tokio::test]`.
+    let configs = match build_config(input.clone(), args, false, rt_multi_thread) {
+        Ok(c) => c,
+        Err(e) => return token_stream_with_error(item, e),
+    };
+
+    parse_knobs(input, false, configs)
+}
+
+pub(crate) fn test(args: TokenStream, item: TokenStream, rt_multi_thread: bool) -> TokenStream {
+    let input = match syn::parse::<syn::ItemFn>(item.clone()) {
+        Ok(it) => it,
+        Err(e) => return token_stream_with_error(item, e),
+    };
+    let args = match syn::parse::<AttributeArgsWrapper>(args) {
+        Ok(it) => it.0,
+        Err(e) => return token_stream_with_error(item, e),
+    };
+
+    let configs = match build_config(input.clone(), args, true, rt_multi_thread) {
+        Ok(c) => c,
+        Err(e) => return token_stream_with_error(item, e),
+    };
+
+    parse_knobs(input, true, configs)
+}
+
+struct AttributeArgsWrapper(AttributeArgs);
+
+impl syn::parse::Parse for AttributeArgsWrapper {
+    fn parse(input: syn::parse::ParseStream<'_>) -> syn::Result<Self> {
+        Ok(Self(AttributeArgs::parse_terminated(input)?))
+    }
+}
+
+fn token_stream_with_error(mut tokens: TokenStream, error: syn::Error) -> TokenStream {
+    tokens.extend(TokenStream::from(error.into_compile_error()));
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_args(src: &str) -> AttributeArgs {
+        syn::parse_str::<AttributeArgsWrapper>(src).unwrap().0
+    }
+
+    fn parse_fn(src: &str) -> syn::ItemFn {
+        syn::parse_str(src).unwrap()
+    }
+
+    #[test]
+    fn default_flavor_is_current_thread_even_when_multi_thread_is_available() {
+        let input = parse_fn("async fn it_works() {}");
+        let configs = build_config(input, AttributeArgs::new(), true, true).unwrap();
+        assert_eq!(configs.len(), 1);
+        assert!(matches!(configs[0].flavors[0], RuntimeFlavor::CurrentThread));
+    }
+
+    #[test]
+    fn requesting_multi_thread_is_rejected() {
+        let input = parse_fn("async fn it_works() {}");
+        let args = parse_args("flavor = \"multi_thread\"");
+        let err = build_config(input, args, true, true).unwrap_err();
+        assert!(err.to_string().contains("not implemented yet"));
+    }
+
+    #[test]
+    fn flavors_list_produces_one_config_per_requested_flavor() {
+        let input = parse_fn("async fn it_works() {}");
+        let args = parse_args("flavors(\"current_thread\")");
+        let configs = build_config(input, args, true, true).unwrap();
+        assert_eq!(configs.len(), 1);
+        assert!(matches!(configs[0].flavors[0], RuntimeFlavor::CurrentThread));
+    }
+
+    #[test]
+    fn multi_flavor_expansion_nests_one_test_per_flavor_in_a_module_named_after_the_function() {
+        let input = parse_fn("async fn it_works() { assert!(true); }");
+        let configs = vec![
+            FinalConfig {
+                flavors: vec![RuntimeFlavor::CurrentThread],
+                worker_threads: None,
+                start_paused: None,
+                crate_name: None,
+                deterministic_seed: None,
+            },
+            FinalConfig {
+                flavors: vec![RuntimeFlavor::Threaded],
+                worker_threads: None,
+                start_paused: None,
+                crate_name: None,
+                deterministic_seed: None,
+            },
+        ];
+
+        let expanded = parse_knobs(input, true, configs).to_string();
+
+        // Variants are nested in a module named after the original
+        // function, not flattened into underscore-joined names, so the
+        // test harness reports `it_works::current_thread` and
+        // `it_works::multi_thread` as distinct tests.
+        assert!(expanded.contains("mod it_works"));
+        assert!(expanded.contains("fn current_thread"));
+        assert!(expanded.contains("fn multi_thread"));
+        assert!(!expanded.contains("it_works_current_thread"));
+    }
+}
@@ -0,0 +1,158 @@
+//! Macros for use with Tokio
+
+#![allow(clippy::needless_doctest_main)]
+#![warn(
+    missing_debug_implementations,
+    missing_docs,
+    rust_2018_idioms,
+    unreachable_pub
+)]
+
+mod entry;
+
+/// Marks async function to be executed by the selected runtime. This macro
+/// helps set up a `Runtime` without requiring the user to use
+/// [Runtime](../tokio/runtime/struct.Runtime.html) or
+/// [Builder](../tokio/runtime/struct.Builder.html) directly.
+///
+/// Note: This macro is designed to be simplistic and targets basic usage.
+/// If the number of options provided are not enough, use
+/// `Builder::new_current_thread()` to build the `Runtime`.
+///
+/// # Current thread runtime
+///
+/// The only scheduler this crate currently implements is the
+/// single-threaded `current_thread` runtime, which is therefore also the
+/// default flavor:
+///
+/// ```
+/// #[tokio::main]
+/// # async fn main() {}
+/// ```
+///
+/// which is equivalent to
+///
+/// ```
+/// #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {}
+/// ```
+///
+/// # Multi-threaded runtime
+///
+/// `flavor = "multi_thread"` is accepted by the attribute parser (so code
+/// written against it keeps typechecking), but this crate doesn't implement
+/// a multi-threaded scheduler yet, so expanding it is a compile error:
+///
+/// ```compile_fail
+/// #[tokio::main(flavor = "multi_thread")]
+/// # async fn main() {}
+/// ```
+#[proc_macro_attribute]
+pub fn main(args: proc_macro::TokenStream, item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    entry::main(args, item, false)
+}
+
+/// Marks async function to be executed by runtime, suitable to test
+/// environment.
+///
+/// ## Multiple runtime flavors
+///
+/// A single test body can be run against more than one runtime flavor by
+/// passing `flavor = "all"`, or an explicit list via e.g.
+/// `flavors("current_thread", "multi_thread")`. One `#[test]` function is
+/// generated per flavor, nested in a module named after the original
+/// function, so the test harness reports them as distinct tests (e.g.
+/// `it_works::current_thread` and `it_works::multi_thread`). Attributes on
+/// the original item, such as `#[should_panic]` or `#[ignore]`, as well as
+/// `worker_threads` and `start_paused`, are preserved on every generated
+/// variant.
+///
+/// Since `multi_thread` isn't implemented yet (see [`main`]), `flavors(...)`
+/// and `flavor = "all"` are only useful today when every flavor they list is
+/// `current_thread`; listing `multi_thread` is a compile error, same as
+/// `#[tokio::main(flavor = "multi_thread")]`.
+///
+/// ```ignore
+/// #[tokio::test(flavors("current_thread", "multi_thread"))]
+/// async fn it_works() {
+///     assert!(true);
+/// }
+/// ```
+///
+/// ## Deterministic scheduling
+///
+/// `#[tokio::test(deterministic, seed = 0x1234)]` runs the test body on the
+/// current-thread scheduler with its ready queue driven by a PRNG seeded
+/// from the given value, instead of FIFO order, so a flaky interleaving can
+/// be reproduced exactly by re-running with the same seed. If `seed` is
+/// omitted, one is generated and printed as `TOKIO_TEST_SEED=...` when the
+/// test panics. `deterministic` is incompatible with `flavor`/`flavors`,
+/// since it always uses the current-thread scheduler.
+///
+/// ```no_run
+/// #[tokio::test(deterministic, seed = 0x1234)]
+/// async fn reproduces_the_same_interleaving() {
+///     assert!(true);
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn test(args: proc_macro::TokenStream, item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    entry::test(args, item, false)
+}
+
+/// Marks main function to be executed by the selected runtime. This macro
+/// helps set up a `Runtime` without requiring the user to use
+/// [Runtime](../tokio/runtime/struct.Runtime.html) or
+/// [Builder](../tokio/runtime/struct.Builder.html) directly.
+///
+/// This macro expands exactly like [main] and accepts the same options.
+#[proc_macro_attribute]
+pub fn main_rt(args: proc_macro::TokenStream, item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    entry::main(args, item, false)
+}
+
+/// Marks async function to be executed by runtime, suitable to test
+/// environment. This macro expands exactly like [test] and accepts the same
+/// options.
+#[proc_macro_attribute]
+pub fn test_rt(args: proc_macro::TokenStream, item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    entry::test(args, item, false)
+}
+
+/// Marks async function to be executed by the minimal, dependency-free
+/// `block_on` executor provided by the `rt-minimal` feature, for use when
+/// the full runtime (`rt`/`rt-multi-thread`) is disabled.
+#[proc_macro_attribute]
+pub fn main_minimal(args: proc_macro::TokenStream, item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    entry::minimal(args, item, false)
+}
+
+/// Marks async test function to be executed by the minimal, dependency-free
+/// `block_on` executor provided by the `rt-minimal` feature, for use when
+/// the full runtime (`rt`/`rt-multi-thread`) is disabled.
+#[proc_macro_attribute]
+pub fn test_minimal(args: proc_macro::TokenStream, item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    entry::minimal(args, item, true)
+}
+
+/// Always fails with the error message below.
+#[proc_macro_attribute]
+pub fn main_fail(_args: proc_macro::TokenStream, _item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    syn::Error::new(
+        proc_macro2::Span::call_site(),
+        "The `#[tokio::main]` macro requires the `rt` or `rt-multi-thread` feature.",
+    )
+    .into_compile_error()
+    .into()
+}
+
+/// Always fails with the error message below.
+#[proc_macro_attribute]
+pub fn test_fail(_args: proc_macro::TokenStream, _item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    syn::Error::new(
+        proc_macro2::Span::call_site(),
+        "The `#[tokio::test]` macro requires the `rt` or `rt-multi-thread` feature.",
+    )
+    .into_compile_error()
+    .into()
+}
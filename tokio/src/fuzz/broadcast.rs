@@ -0,0 +1,131 @@
+use super::ops::Corpus;
+
+enum Op {
+    Send,
+    RecvOn(usize),
+    Subscribe,
+}
+
+impl Op {
+    const COUNT: usize = 3;
+
+    fn decode(corpus: &mut Corpus<'_>, n: usize) -> Option<Op> {
+        Some(match n {
+            0 => Op::Send,
+            1 => Op::RecvOn(corpus.next_byte()? as usize),
+            _ => Op::Subscribe,
+        })
+    }
+}
+
+const CAPACITY: usize = 4;
+
+/// One ring-buffer slot, reused every `CAPACITY` sends as the wheel rotates
+/// — mirrors `broadcast::Shared`'s `buffer: Box<[RwLock<Slot<T>>]>`, where
+/// each slot tracks the absolute send position it currently holds (so a
+/// reader can tell whether the value it wants is still there or has
+/// already been overwritten by a later send).
+struct Slot {
+    pos: u64,
+    value: Option<u64>,
+}
+
+/// The shared ring buffer backing the channel, shaped like the real
+/// `broadcast::Shared`: a fixed-size buffer of slots plus the next
+/// absolute position to write.
+struct Shared {
+    buffer: Box<[Slot]>,
+    tail: u64,
+}
+
+impl Shared {
+    fn new(capacity: usize) -> Self {
+        Self {
+            buffer: (0..capacity)
+                .map(|i| Slot {
+                    pos: i as u64,
+                    value: None,
+                })
+                .collect(),
+            tail: 0,
+        }
+    }
+
+    fn send(&mut self) {
+        let pos = self.tail;
+        let idx = (pos as usize) % self.buffer.len();
+        self.buffer[idx] = Slot { pos, value: Some(pos) };
+        self.tail += 1;
+    }
+
+    /// Reads the value written at `pos`, or `None` if `pos` has already
+    /// been overwritten by a later send (the slot now holds a different
+    /// position).
+    fn get(&self, pos: u64) -> Option<u64> {
+        let slot = &self.buffer[(pos as usize) % self.buffer.len()];
+        if slot.pos == pos {
+            slot.value
+        } else {
+            None
+        }
+    }
+}
+
+struct Receiver {
+    next: u64,
+}
+
+/// Fuzzes the `broadcast` channel state machine against a real fixed-size
+/// ring buffer (`Shared`, shaped like the actual `broadcast::Shared`'s
+/// `buffer`/`tail`) rather than a `VecDeque` model of it: sends, per-receiver
+/// `recv`s, and new subscriptions must keep every receiver's view consistent
+/// with the shared buffer — each receiver either sees every value sent
+/// after it subscribed, in order, or (once a slot it wanted has been
+/// overwritten) is marked lagged and resynchronized to the oldest position
+/// still live in the buffer, but never observes a value out of order or
+/// from before it subscribed.
+pub fn fuzz_broadcast(ops: &[u8]) {
+    let mut corpus = Corpus::new(ops);
+    let mut shared = Shared::new(CAPACITY);
+    let mut receivers: Vec<Receiver> = Vec::new();
+
+    while let Some(choice) = corpus.next_choice(Op::COUNT) {
+        let Some(op) = Op::decode(&mut corpus, choice) else {
+            break;
+        };
+        match op {
+            Op::Send => {
+                shared.send();
+            }
+            Op::Subscribe => {
+                receivers.push(Receiver { next: shared.tail });
+            }
+            Op::RecvOn(raw) => {
+                if receivers.is_empty() {
+                    continue;
+                }
+                let i = raw % receivers.len();
+                let receiver = &mut receivers[i];
+                if receiver.next >= shared.tail {
+                    continue; // nothing new yet
+                }
+                match shared.get(receiver.next) {
+                    Some(value) => {
+                        // The value at `next` must equal what the shared
+                        // buffer says was sent at that position.
+                        assert_eq!(value, receiver.next);
+                        receiver.next += 1;
+                    }
+                    None => {
+                        // Lagged: the slot `next` wanted has been
+                        // overwritten by a later send. Resync to the
+                        // oldest position the buffer still holds.
+                        let oldest = shared.tail.saturating_sub(shared.buffer.len() as u64);
+                        assert!(receiver.next < oldest, "receiver reported lagged but wasn't actually behind the buffer");
+                        receiver.next = oldest;
+                    }
+                }
+            }
+        }
+    }
+}
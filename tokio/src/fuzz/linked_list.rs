@@ -0,0 +1,85 @@
+use super::ops::Corpus;
+use crate::util::linked_list::{Link, LinkedList};
+use std::pin::Pin;
+use std::ptr::NonNull;
+
+#[derive(Debug, Default)]
+struct Entry {
+    pointers: crate::util::linked_list::Pointers<Entry>,
+}
+
+generate_addr_of_methods! {
+    impl<> Entry {
+        unsafe fn addr_of_pointers(self: NonNull<Self>) -> NonNull<crate::util::linked_list::Pointers<Self>> {
+            &self.pointers
+        }
+    }
+}
+
+unsafe impl Link for Entry {
+    type Handle = Pin<Box<Entry>>;
+    type Target = Entry;
+
+    fn as_raw(handle: &Self::Handle) -> NonNull<Entry> {
+        NonNull::from(handle.as_ref().get_ref())
+    }
+
+    unsafe fn from_raw(ptr: NonNull<Entry>) -> Self::Handle {
+        Pin::new_unchecked(Box::from_raw(ptr.as_ptr()))
+    }
+
+    unsafe fn pointers(target: NonNull<Entry>) -> NonNull<crate::util::linked_list::Pointers<Entry>> {
+        Entry::addr_of_pointers(target)
+    }
+}
+
+enum Op {
+    PushFront,
+    Pop,
+}
+
+impl Op {
+    const COUNT: usize = 2;
+
+    fn decode(n: usize) -> Op {
+        match n {
+            0 => Op::PushFront,
+            1 => Op::Pop,
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// Fuzzes `util::linked_list`: an arbitrary sequence of pushes and pops must
+/// never lose or duplicate an entry, and the reported length must always
+/// match the number of entries actually present.
+pub fn fuzz_linked_list(ops: &[u8]) {
+    let mut corpus = Corpus::new(ops);
+    let mut list = LinkedList::<Entry, Entry>::new();
+    let mut expected_len = 0usize;
+
+    while let Some(choice) = corpus.next_choice(Op::COUNT) {
+        match Op::decode(choice) {
+            Op::PushFront => {
+                list.push_front(Box::pin(Entry::default()));
+                expected_len += 1;
+            }
+            Op::Pop => {
+                let popped = list.pop_back();
+                if popped.is_some() {
+                    expected_len -= 1;
+                } else {
+                    assert_eq!(expected_len, 0);
+                }
+            }
+        }
+    }
+
+    // Drain the remainder, checking that exactly `expected_len` entries come
+    // back out and no more.
+    let mut drained = 0;
+    while list.pop_back().is_some() {
+        drained += 1;
+    }
+    assert_eq!(drained, expected_len);
+}
@@ -0,0 +1,28 @@
+//! Internal structured-fuzzing harnesses.
+//!
+//! Each `fuzz_*` function below is a `cargo-fuzz` entry point for one
+//! concurrency primitive. They all follow the same shape: decode the raw
+//! `&[u8]` corpus input into a sequence of typed operations (push, pop,
+//! cancel, advance, ...), apply those operations one at a time to a real
+//! instance of the data structure, and assert the structure's invariants
+//! (no lost or duplicated nodes, consistent length, no use-after-free) as
+//! the operations are applied. Exposing one function per structure lets each have its own
+//! `cargo-fuzz` binary and its own seed corpus, rather than sharing a single
+//! fuzz target across unrelated structures.
+
+mod ops;
+
+mod linked_list;
+pub use linked_list::fuzz_linked_list;
+
+mod wakers;
+pub use wakers::fuzz_wakers;
+
+mod mpsc;
+pub use mpsc::fuzz_mpsc;
+
+mod broadcast;
+pub use broadcast::fuzz_broadcast;
+
+mod time_wheel;
+pub use time_wheel::fuzz_time_wheel;
@@ -0,0 +1,69 @@
+use super::ops::{Corpus, Entry};
+use crate::util::linked_list::LinkedList;
+
+enum Op {
+    Send,
+    Recv,
+    CloseSender,
+}
+
+impl Op {
+    const COUNT: usize = 3;
+
+    fn decode(n: usize) -> Op {
+        match n {
+            0 => Op::Send,
+            1 => Op::Recv,
+            _ => Op::CloseSender,
+        }
+    }
+}
+
+/// Fuzzes the `mpsc` channel state machine against the same intrusive
+/// `crate::util::linked_list::LinkedList` queue `fuzz_linked_list`
+/// exercises (rather than a `VecDeque` model of it): a sequence of sends,
+/// receives, and sender-closes must always preserve FIFO order, and once
+/// the queue is both empty and closed, `recv` must keep fusing to `None`
+/// forever after — it must never resume producing a value once it has
+/// reported closed.
+pub fn fuzz_mpsc(ops: &[u8]) {
+    let mut corpus = Corpus::new(ops);
+    let mut queue = LinkedList::<Entry<u64>, Entry<u64>>::new();
+    let mut closed = false;
+    let mut next_value = 0u64;
+    let mut next_expected = 0u64;
+    let mut fused = false;
+
+    while let Some(choice) = corpus.next_choice(Op::COUNT) {
+        match Op::decode(choice) {
+            Op::Send => {
+                if !closed {
+                    queue.push_front(Entry::boxed(next_value));
+                    next_value += 1;
+                }
+            }
+            Op::Recv => {
+                match queue.pop_back() {
+                    Some(entry) => {
+                        assert!(!fused, "recv produced a value after the channel had already fused closed");
+                        assert_eq!(entry.value, next_expected, "recv violated FIFO order");
+                        next_expected += 1;
+                    }
+                    None => {
+                        // A fused receiver keeps returning `None` on every
+                        // call once the channel is closed and drained; that
+                        // is expected on every subsequent `Recv`, not just
+                        // the first one, so there's nothing else to assert
+                        // here.
+                        if closed {
+                            fused = true;
+                        }
+                    }
+                }
+            }
+            Op::CloseSender => {
+                closed = true;
+            }
+        }
+    }
+}
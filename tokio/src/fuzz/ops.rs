@@ -0,0 +1,78 @@
+//! Shared corpus decoding helpers used by the `fuzz_*` harnesses.
+//!
+//! Each harness turns an arbitrary `&[u8]` into a finite sequence of typed
+//! operations by reading one byte at a time: one byte selects which
+//! operation to run next (modulo the number of operation variants for that
+//! structure), and, for operations that carry a payload, a following byte
+//! supplies it. Running out of input ends the sequence early rather than
+//! panicking, so every byte string is a valid (if possibly short) input.
+
+/// A tiny cursor over the fuzzer-provided corpus.
+pub(super) struct Corpus<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Corpus<'a> {
+    pub(super) fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    /// Returns the next byte, or `None` if the corpus is exhausted.
+    pub(super) fn next_byte(&mut self) -> Option<u8> {
+        let byte = *self.data.get(self.pos)?;
+        self.pos += 1;
+        Some(byte)
+    }
+
+    /// Picks one of `variants` operation kinds using the next byte.
+    pub(super) fn next_choice(&mut self, variants: usize) -> Option<usize> {
+        debug_assert!(variants > 0);
+        Some(self.next_byte()? as usize % variants)
+    }
+}
+
+/// A generic intrusive-list node, reused by the `fuzz_wakers`, `fuzz_mpsc`,
+/// and `fuzz_time_wheel` harnesses so each drives
+/// `crate::util::linked_list::LinkedList` directly (the same primitive real
+/// waiter lists, channel queues, and timer wheel slots use) instead of
+/// reimplementing a toy model of it.
+#[derive(Debug, Default)]
+pub(super) struct Entry<T> {
+    pointers: crate::util::linked_list::Pointers<Entry<T>>,
+    pub(super) value: T,
+}
+
+impl<T: Default> Entry<T> {
+    pub(super) fn boxed(value: T) -> std::pin::Pin<Box<Entry<T>>> {
+        Box::pin(Entry {
+            value,
+            ..Default::default()
+        })
+    }
+}
+
+generate_addr_of_methods! {
+    impl<T> Entry<T> {
+        unsafe fn addr_of_pointers(self: std::ptr::NonNull<Self>) -> std::ptr::NonNull<crate::util::linked_list::Pointers<Self>> {
+            &self.pointers
+        }
+    }
+}
+
+unsafe impl<T> crate::util::linked_list::Link for Entry<T> {
+    type Handle = std::pin::Pin<Box<Entry<T>>>;
+    type Target = Entry<T>;
+
+    fn as_raw(handle: &Self::Handle) -> std::ptr::NonNull<Entry<T>> {
+        std::ptr::NonNull::from(handle.as_ref().get_ref())
+    }
+
+    unsafe fn from_raw(ptr: std::ptr::NonNull<Entry<T>>) -> Self::Handle {
+        std::pin::Pin::new_unchecked(Box::from_raw(ptr.as_ptr()))
+    }
+
+    unsafe fn pointers(target: std::ptr::NonNull<Entry<T>>) -> std::ptr::NonNull<crate::util::linked_list::Pointers<Entry<T>>> {
+        Entry::addr_of_pointers(target)
+    }
+}
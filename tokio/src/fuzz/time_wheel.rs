@@ -0,0 +1,113 @@
+use super::ops::{Corpus, Entry};
+use crate::util::linked_list::LinkedList;
+use std::collections::{HashMap, HashSet};
+use std::ptr::NonNull;
+
+enum Op {
+    Insert { ticks_from_now: u8 },
+    Advance { ticks: u8 },
+    Cancel { id: u8 },
+}
+
+impl Op {
+    const COUNT: usize = 3;
+
+    fn decode(corpus: &mut Corpus<'_>, n: usize) -> Option<Op> {
+        Some(match n {
+            0 => Op::Insert {
+                ticks_from_now: corpus.next_byte()?,
+            },
+            1 => Op::Advance {
+                ticks: corpus.next_byte()?,
+            },
+            _ => Op::Cancel {
+                id: corpus.next_byte()?,
+            },
+        })
+    }
+}
+
+const NUM_SLOTS: usize = 64;
+
+type Slot = LinkedList<Entry<u8>, Entry<u8>>;
+
+/// Fuzzes the timer wheel's slot rotation against real intrusive-list
+/// slots (`crate::util::linked_list::LinkedList`, the same primitive the
+/// actual timer wheel's slots are built on) rather than a
+/// `Vec<Vec<(u8, u64)>>` model of them: entries are linked into the slot
+/// for their deadline, the wheel is advanced by an arbitrary number of
+/// ticks (unlinking and firing every entry in the now-current slot whose
+/// deadline has arrived), and entries can be cancelled — unlinked directly
+/// via their own pointer, exactly as a dropped `Sleep` cancels itself —
+/// before they fire. An entry must fire exactly once, on the tick matching
+/// its deadline, and a cancelled entry must never fire.
+pub fn fuzz_time_wheel(ops: &[u8]) {
+    let mut corpus = Corpus::new(ops);
+    let mut slots: Vec<Slot> = (0..NUM_SLOTS).map(|_| LinkedList::new()).collect();
+    let mut now: u64 = 0;
+    // id -> (slot index, deadline, pointer into that slot's list).
+    let mut entries: HashMap<u8, (usize, u64, NonNull<Entry<u8>>)> = HashMap::new();
+    let mut fired: HashMap<u8, u64> = HashMap::new();
+    let mut cancelled: HashSet<u8> = HashSet::new();
+    let mut next_id = 0u8;
+
+    while let Some(choice) = corpus.next_choice(Op::COUNT) {
+        let Some(op) = Op::decode(&mut corpus, choice) else {
+            break;
+        };
+        match op {
+            Op::Insert { ticks_from_now } => {
+                let id = next_id;
+                next_id = next_id.wrapping_add(1);
+                // Ids wrap after 256 inserts. Skip an id whose entry is still
+                // live, and forget what an earlier entry with this id did.
+                if entries.contains_key(&id) {
+                    continue;
+                }
+                fired.remove(&id);
+                cancelled.remove(&id);
+                // Tick `now` has already been processed, so the earliest
+                // tick an entry can fire on is `now + 1`.
+                let deadline = now + (ticks_from_now as u64).max(1);
+                let slot = (deadline as usize) % NUM_SLOTS;
+
+                let entry = Entry::boxed(id);
+                let ptr = NonNull::from(entry.as_ref().get_ref());
+                slots[slot].push_front(entry);
+                entries.insert(id, (slot, deadline, ptr));
+            }
+            Op::Advance { ticks } => {
+                for _ in 0..ticks {
+                    now += 1;
+                    let slot = (now as usize) % NUM_SLOTS;
+                    let due: Vec<u8> = entries
+                        .iter()
+                        .filter(|(_, &(entry_slot, deadline, _))| entry_slot == slot && deadline <= now)
+                        .map(|(&id, _)| id)
+                        .collect();
+
+                    for id in due {
+                        let (_, deadline, ptr) = entries.remove(&id).expect("id just collected from `entries`");
+                        // SAFETY: `ptr` was linked into `slots[slot]` at
+                        // insert time and hasn't been removed since (it was
+                        // still present in `entries` above).
+                        let removed = unsafe { slots[slot].remove(ptr) };
+                        assert!(removed.is_some(), "entry's slot pointer was already unlinked");
+
+                        assert_eq!(deadline, now, "entry fired on the wrong tick");
+                        assert!(!cancelled.contains(&id), "a cancelled entry fired");
+                        assert!(fired.insert(id, now).is_none(), "entry fired more than once");
+                    }
+                }
+            }
+            Op::Cancel { id } => {
+                if let Some((slot, _, ptr)) = entries.remove(&id) {
+                    // SAFETY: as above.
+                    let removed = unsafe { slots[slot].remove(ptr) };
+                    assert!(removed.is_some(), "cancelled entry's slot pointer was already unlinked");
+                    cancelled.insert(id);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use super::ops::{Corpus, Entry};
+use crate::util::linked_list::LinkedList;
+use std::collections::HashSet;
+use std::ptr::NonNull;
+
+enum Op {
+    Push,
+    Pop,
+    Cancel(usize),
+}
+
+impl Op {
+    const COUNT: usize = 3;
+
+    fn decode(corpus: &mut Corpus<'_>, n: usize) -> Option<Op> {
+        Some(match n {
+            0 => Op::Push,
+            1 => Op::Pop,
+            _ => Op::Cancel(corpus.next_byte()? as usize),
+        })
+    }
+}
+
+/// Fuzzes the intrusive wakers list shared by `Notify` and the semaphore,
+/// applied to the actual `crate::util::linked_list::LinkedList` rather than
+/// a `VecDeque` model of it: pushing, popping (simulating a wake), and
+/// cancelling a waiter at an arbitrary position — by unlinking the node via
+/// its own pointer, exactly as a dropped waiter removes itself — must never
+/// lose a node, duplicate one, or leave a dangling entry in the list.
+pub fn fuzz_wakers(ops: &[u8]) {
+    let mut corpus = Corpus::new(ops);
+    let mut list = LinkedList::<Entry<u64>, Entry<u64>>::new();
+    // Pointers into nodes currently linked into `list`. A real waiter holds
+    // onto its own node this way so it can unlink itself in O(1) on cancel,
+    // rather than the list being scanned for it.
+    let mut live: Vec<NonNull<Entry<u64>>> = Vec::new();
+    let mut next_id = 0u64;
+
+    while let Some(choice) = corpus.next_choice(Op::COUNT) {
+        let Some(op) = Op::decode(&mut corpus, choice) else {
+            break;
+        };
+        match op {
+            Op::Push => {
+                let entry = Entry::boxed(next_id);
+                next_id += 1;
+                let ptr = NonNull::from(entry.as_ref().get_ref());
+                list.push_front(entry);
+                live.push(ptr);
+            }
+            Op::Pop => {
+                if let Some(entry) = list.pop_back() {
+                    let ptr = NonNull::from(entry.as_ref().get_ref());
+                    let before = live.len();
+                    live.retain(|p| *p != ptr);
+                    assert_eq!(live.len(), before - 1, "popped a node `live` didn't know about");
+                }
+            }
+            Op::Cancel(raw_index) => {
+                if !live.is_empty() {
+                    let index = raw_index % live.len();
+                    let ptr = live.remove(index);
+                    // SAFETY: `ptr` was pushed into `list` above and hasn't
+                    // been removed since (it's still in `live`).
+                    let removed = unsafe { list.remove(ptr) };
+                    assert!(removed.is_some(), "cancelled node was not actually linked");
+                }
+            }
+        }
+
+        // No duplicate node addresses may ever be tracked as live at once.
+        let unique: HashSet<_> = live.iter().collect();
+        assert_eq!(unique.len(), live.len(), "waker list contains a duplicate entry");
+    }
+}
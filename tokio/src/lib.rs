@@ -211,18 +211,30 @@
 //! ```
 //!
 //! If your code is CPU-bound and you wish to limit the number of threads used
-//! to run it, you should use a separate thread pool dedicated to CPU bound tasks.
-//! For example, you could consider using the [rayon] library for CPU-bound
-//! tasks. It is also possible to create an extra Tokio runtime dedicated to
-//! CPU-bound tasks, but if you do this, you should be careful that the extra
-//! runtime runs _only_ CPU-bound tasks, as IO-bound tasks on that runtime
-//! will behave poorly.
+//! to run it, you should use [`task::spawn_compute`] to run the work on a
+//! dedicated compute pool. Unlike the blocking thread pool, the compute pool
+//! is bounded (sized after the number of available CPUs by default) and is
+//! intended for parallel, CPU-bound work rather than blocking I/O. The pool
+//! can be configured through [`Builder::max_compute_threads`],
+//! [`Builder::compute_thread_name`], and [`Builder::compute_stack_size`].
 //!
-//! Hint: If using rayon, you can use a [`oneshot`] channel to send the result back
-//! to Tokio when the rayon task finishes.
+//! ```
+//! # async fn dox() {
+//! let result = tokio::task::spawn_compute(|| {
+//!     // CPU-bound work happens here, on the compute pool.
+//!     (0..1_000_000u64).sum::<u64>()
+//! }).await.unwrap();
+//! # let _ = result;
+//! # }
+//! ```
 //!
-//! [rayon]: https://docs.rs/rayon
-//! [`oneshot`]: crate::sync::oneshot
+//! A panic inside the closure is caught and propagated through the returned
+//! [`JoinHandle`] the same way a panic in [`spawn_blocking`] is.
+//!
+//! [`task::spawn_compute`]: crate::task::spawn_compute()
+//! [`Builder::max_compute_threads`]: crate::runtime::Builder::max_compute_threads
+//! [`Builder::compute_thread_name`]: crate::runtime::Builder::compute_thread_name
+//! [`Builder::compute_stack_size`]: crate::runtime::Builder::compute_stack_size
 //!
 //! ## Asynchronous IO
 //!
@@ -318,6 +330,10 @@
 //! - `rt`: Enables `tokio::spawn`, the current-thread scheduler,
 //!   and non-scheduler utilities.
 //! - `rt-multi-thread`: Enables the heavier, multi-threaded, work-stealing scheduler.
+//! - `rt-minimal`: Enables a dependency-free, single-threaded `block_on`
+//!   executor with no work-stealing, timers, or I/O driver, for use by
+//!   `#[tokio::main]`/`#[tokio::test]` when `rt` is disabled. Not meant to
+//!   be used together with `rt` or `rt-multi-thread`.
 //! - `io-util`: Enables the IO based `Ext` traits.
 //! - `io-std`: Enable `Stdout`, `Stdin` and `Stderr` types.
 //! - `net`: Enables `tokio::net` types such as `TcpStream`, `UnixStream` and
@@ -480,14 +496,19 @@ compile_error!("The `tokio_taskdump` feature requires `--cfg tokio_unstable`.");
 #[cfg(all(
     tokio_taskdump,
     not(doc),
-    not(all(
-        target_os = "linux",
-        any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64")
+    not(any(
+        all(
+            target_os = "linux",
+            any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64")
+        ),
+        all(target_os = "macos", any(target_arch = "aarch64", target_arch = "x86_64")),
+        all(target_os = "windows", target_arch = "x86_64"),
     ))
 ))]
 compile_error!(
     "The `tokio_taskdump` feature is only currently supported on \
-linux, on `aarch64`, `x86` and `x86_64`."
+linux (on `aarch64`, `x86` and `x86_64`), macOS (on `aarch64` and `x86_64`), \
+and Windows (on `x86_64`)."
 );
 
 // Includes re-exports used by macros.
@@ -549,8 +570,13 @@ cfg_not_sync! {
 pub mod task;
 cfg_rt! {
     pub use task::spawn;
+    pub use task::spawn_compute;
 }
 
+#[cfg(all(feature = "rt-minimal", not(feature = "rt")))]
+#[doc(hidden)]
+pub mod rt_minimal;
+
 cfg_time! {
     pub mod time;
 }
@@ -589,6 +615,7 @@ mod trace {
     }
 }
 
+#[macro_use]
 mod util;
 
 /// Due to the `Stream` trait's inclusion in `std` landing later than Tokio's 1.0
@@ -678,11 +705,24 @@ cfg_macros! {
         }
     }
 
-    // Always fail if rt is not enabled.
     cfg_not_rt! {
+        // With the full runtime disabled but `rt-minimal` enabled, `main`
+        // and `test` target the dependency-free, single-threaded executor
+        // in `rt_minimal` instead of `runtime::Builder`.
+        #[cfg(feature = "rt-minimal")]
+        #[doc(inline)]
+        pub use tokio_macros::main_minimal as main;
+
+        #[cfg(feature = "rt-minimal")]
+        #[doc(inline)]
+        pub use tokio_macros::test_minimal as test;
+
+        // Always fail if neither `rt` nor `rt-minimal` is enabled.
+        #[cfg(not(feature = "rt-minimal"))]
         #[doc(inline)]
         pub use tokio_macros::main_fail as main;
 
+        #[cfg(not(feature = "rt-minimal"))]
         #[doc(inline)]
         pub use tokio_macros::test_fail as test;
     }
@@ -693,6 +733,8 @@ cfg_macros! {
 #[cfg(test)]
 fn is_unpin<T: Unpin>() {}
 
-/// fuzz test (`fuzz_linked_list`)
+/// Internal structured-fuzzing harnesses for the concurrency primitives
+/// (`fuzz_linked_list`, `fuzz_wakers`, `fuzz_mpsc`, `fuzz_broadcast`,
+/// `fuzz_time_wheel`). See the module docs for details.
 #[cfg(fuzzing)]
 pub mod fuzz;
@@ -0,0 +1,45 @@
+//! A minimal, dependency-free `block_on` executor.
+//!
+//! This is not a real scheduler: there is no work-stealing, no timer, and no
+//! I/O driver. It exists solely so that `#[tokio::main]` and
+//! `#[tokio::test]` have something to expand into when the `rt` feature is
+//! disabled but the caller still wants `async`/`await` syntax at the entry
+//! point (for example, a doctest, or a tiny binary that only awaits a single
+//! leaf future). Prefer the full runtime (the `rt` feature) for anything
+//! that spawns tasks or performs I/O.
+//!
+//! This module is `doc(hidden)` and is only an implementation detail of the
+//! `#[tokio::main]`/`#[tokio::test]` macros; it is not meant to be used
+//! directly.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use crate::runtime::park::{waker_from_parker, Parker};
+
+/// Blocks the current thread until the given future completes, driving it
+/// with a trivial park/unpark waker.
+///
+/// # Panics
+///
+/// This does not panic on its own, but (unlike the full runtime) it has no
+/// way to detect a future that will never make progress; such a future will
+/// block the thread forever.
+pub fn block_on<F: Future>(mut future: F) -> F::Output {
+    let parker = Arc::new(Parker::new());
+    let waker = waker_from_parker(parker.clone());
+    let mut cx = Context::from_waker(&waker);
+
+    // Safety: `future` is not moved again after being pinned here; it is a
+    // local that lives until this function returns.
+    let mut future = unsafe { Pin::new_unchecked(&mut future) };
+
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => parker.park(),
+        }
+    }
+}
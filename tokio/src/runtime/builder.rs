@@ -0,0 +1,119 @@
+use crate::runtime::compute_pool::{self, Config as ComputePoolConfig};
+use crate::runtime::Runtime;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    CurrentThread,
+    MultiThread,
+}
+
+/// Builds a Tokio runtime.
+#[derive(Debug)]
+pub struct Builder {
+    kind: Kind,
+    worker_threads: Option<usize>,
+    start_paused: bool,
+    compute_pool: ComputePoolConfig,
+    deterministic_seed: Option<u64>,
+}
+
+impl Builder {
+    /// Returns a new builder with the current-thread scheduler selected.
+    pub fn new_current_thread() -> Builder {
+        Builder::new(Kind::CurrentThread)
+    }
+
+    /// Returns a new builder with the multi-thread scheduler selected.
+    ///
+    /// This scheduler isn't implemented yet; [`build`](Builder::build) on
+    /// the result always returns an error.
+    pub fn new_multi_thread() -> Builder {
+        Builder::new(Kind::MultiThread)
+    }
+
+    fn new(kind: Kind) -> Builder {
+        Builder {
+            kind,
+            worker_threads: None,
+            start_paused: false,
+            compute_pool: ComputePoolConfig::default(),
+            deterministic_seed: None,
+        }
+    }
+
+    /// Records a number of worker threads. The built runtime runs on the
+    /// calling thread only and does not use this value.
+    pub fn worker_threads(&mut self, val: usize) -> &mut Self {
+        self.worker_threads = Some(val);
+        self
+    }
+
+    /// Accepted for compatibility. The built runtime has no I/O or time
+    /// driver, so this does nothing.
+    pub fn enable_all(&mut self) -> &mut Self {
+        self
+    }
+
+    /// Records whether the runtime starts with time paused. The built
+    /// runtime does not drive `tokio::time`, so the flag has no effect on it.
+    pub fn start_paused(&mut self, val: bool) -> &mut Self {
+        self.start_paused = val;
+        self
+    }
+
+    /// Sets the maximum number of threads used by the compute pool (see
+    /// [`task::spawn_compute`](crate::task::spawn_compute())). Defaults to
+    /// the number of available CPUs.
+    pub fn max_compute_threads(&mut self, val: usize) -> &mut Self {
+        self.compute_pool.max_threads = val;
+        self
+    }
+
+    /// Sets the thread name prefix used by compute pool worker threads.
+    pub fn compute_thread_name(&mut self, val: impl Into<String>) -> &mut Self {
+        self.compute_pool.thread_name = val.into();
+        self
+    }
+
+    /// Sets the stack size (in bytes) used by compute pool worker threads.
+    pub fn compute_stack_size(&mut self, val: usize) -> &mut Self {
+        self.compute_pool.stack_size = Some(val);
+        self
+    }
+
+    cfg_test_util! {
+        /// Seeds the built runtime's scheduler with a deterministic,
+        /// pseudo-random ready-queue ordering (see
+        /// [`runtime::deterministic`](crate::runtime::deterministic)),
+        /// so the same seed against the same test body always reproduces
+        /// the same task interleaving. Set by `#[tokio::test(deterministic,
+        /// seed = ...)]`.
+        pub fn deterministic_seed(&mut self, seed: u64) -> &mut Self {
+            self.deterministic_seed = Some(seed);
+            self
+        }
+    }
+
+    /// Builds the configured runtime.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if this builder was created with
+    /// [`new_multi_thread`](Builder::new_multi_thread): this crate doesn't
+    /// implement a multi-threaded scheduler yet, only `current_thread`.
+    pub fn build(&self) -> std::io::Result<Runtime> {
+        if self.kind == Kind::MultiThread {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "the multi-thread runtime flavor is not implemented yet; use Builder::new_current_thread()",
+            ));
+        }
+
+        // The compute pool is process-wide rather than per-`Runtime` (see
+        // `runtime::compute_pool`); the first `Builder` to reach here wins,
+        // same as any other global-once initialization.
+        compute_pool::init_global(&self.compute_pool);
+
+        Ok(Runtime::new(self.start_paused, self.deterministic_seed))
+    }
+}
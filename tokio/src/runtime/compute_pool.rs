@@ -0,0 +1,188 @@
+//! The compute pool backing [`task::spawn_compute`](crate::task::spawn_compute()).
+//!
+//! This is a dedicated, bounded thread pool for CPU-bound closures, kept
+//! separate from both the core scheduler threads and the unbounded
+//! `spawn_blocking` pool: worker threads pull jobs off a single shared
+//! queue until the pool is dropped, and a panic inside a job is caught and
+//! delivered through the corresponding [`JoinHandle`](crate::task::JoinHandle)
+//! instead of taking down the worker thread.
+
+use crate::task::join::{JoinHandle, Shared};
+use std::panic::AssertUnwindSafe;
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::thread;
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// Compute-pool configuration, set via
+/// [`Builder::max_compute_threads`](crate::runtime::Builder::max_compute_threads)
+/// and friends.
+#[derive(Debug, Clone)]
+pub(crate) struct Config {
+    pub(crate) max_threads: usize,
+    pub(crate) thread_name: String,
+    pub(crate) stack_size: Option<usize>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            max_threads: available_parallelism(),
+            thread_name: "tokio-compute".to_string(),
+            stack_size: None,
+        }
+    }
+}
+
+fn available_parallelism() -> usize {
+    thread::available_parallelism().map(|n| n.get()).unwrap_or(1)
+}
+
+/// A dedicated, CPU-count-bounded thread pool for CPU-bound closures.
+pub(crate) struct ComputePool {
+    // Wrapped in `Option` so `Drop` can close the channel (by dropping the
+    // sender) before joining the worker threads; otherwise every worker's
+    // blocking `recv` would wait forever on a channel we still hold open.
+    sender: Option<mpsc::Sender<Job>>,
+    workers: Vec<thread::JoinHandle<()>>,
+}
+
+impl ComputePool {
+    pub(crate) fn new(config: &Config) -> Self {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        let workers = (0..config.max_threads.max(1))
+            .map(|i| {
+                let receiver = Arc::clone(&receiver);
+                let mut builder =
+                    thread::Builder::new().name(format!("{}-{}", config.thread_name, i));
+                if let Some(stack_size) = config.stack_size {
+                    builder = builder.stack_size(stack_size);
+                }
+                builder
+                    .spawn(move || worker_loop(receiver))
+                    .expect("failed to spawn compute pool thread")
+            })
+            .collect();
+
+        Self {
+            sender: Some(sender),
+            workers,
+        }
+    }
+
+    pub(crate) fn spawn<F, R>(&self, f: F) -> JoinHandle<R>
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let shared = Shared::new();
+        let job_shared = Arc::clone(&shared);
+
+        let job: Job = Box::new(move || {
+            let result = std::panic::catch_unwind(AssertUnwindSafe(f));
+            job_shared.complete(result);
+        });
+
+        self.sender
+            .as_ref()
+            .expect("sender is only `None` after the pool starts shutting down")
+            .send(job)
+            .expect("compute pool has shut down");
+
+        JoinHandle::new(shared)
+    }
+}
+
+impl Drop for ComputePool {
+    fn drop(&mut self) {
+        // Closing the channel unblocks every worker's `recv`, letting its
+        // loop exit; only then do we join them, so the pool's threads never
+        // outlive the pool itself.
+        self.sender = None;
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+fn worker_loop(receiver: Arc<Mutex<mpsc::Receiver<Job>>>) {
+    loop {
+        let job = {
+            let receiver = receiver.lock().unwrap();
+            receiver.recv()
+        };
+        match job {
+            Ok(job) => job(),
+            Err(_) => return,
+        }
+    }
+}
+
+static GLOBAL: OnceLock<ComputePool> = OnceLock::new();
+
+/// Returns the process-wide compute pool, initializing it with the default
+/// configuration on first use if no [`Builder`](crate::runtime::Builder)
+/// has configured (and built) one yet.
+pub(crate) fn global() -> &'static ComputePool {
+    GLOBAL.get_or_init(|| ComputePool::new(&Config::default()))
+}
+
+/// Installs `config` as the process-wide compute pool, if one hasn't
+/// already been installed. Called from [`Builder::build`](crate::runtime::Builder::build).
+pub(crate) fn init_global(config: &Config) {
+    let _ = GLOBAL.set(ComputePool::new(config));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::park::{waker_from_parker, Parker};
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    fn block_on<F: Future>(mut future: F) -> F::Output {
+        let parker = Arc::new(Parker::new());
+        let waker = waker_from_parker(parker.clone());
+        let mut cx = Context::from_waker(&waker);
+        let mut future = unsafe { Pin::new_unchecked(&mut future) };
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => parker.park(),
+            }
+        }
+    }
+
+    fn single_threaded_pool() -> ComputePool {
+        ComputePool::new(&Config {
+            max_threads: 1,
+            ..Config::default()
+        })
+    }
+
+    #[test]
+    fn spawn_returns_the_closures_result() {
+        let pool = single_threaded_pool();
+        let handle = pool.spawn(|| 2 + 2);
+        assert_eq!(block_on(handle).unwrap(), 4);
+    }
+
+    #[test]
+    fn a_panic_in_the_closure_propagates_through_the_join_handle() {
+        // Silence the expected panic's default stderr report; the pool's
+        // worker thread still observes and catches it via `catch_unwind`.
+        let previous_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(|_| {}));
+
+        let pool = single_threaded_pool();
+        let handle = pool.spawn(|| panic!("boom"));
+        let err = block_on(handle).unwrap_err();
+
+        std::panic::set_hook(previous_hook);
+        assert!(err.is_panic());
+    }
+}
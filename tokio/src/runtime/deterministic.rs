@@ -0,0 +1,223 @@
+//! A seeded pseudo-random ready-queue ordering, used by
+//! `#[tokio::test(deterministic, ...)]` to make task interleavings
+//! reproducible across runs.
+//!
+//! Instead of popping the next ready task off the front of the queue (FIFO),
+//! [`DeterministicScheduler`] keeps its ready queue as a `Vec` and pops a
+//! pseudo-random index on each scheduling step, using [`Xorshift`] seeded
+//! from the attribute (or from a caller-supplied seed, printed on panic so a
+//! flaky interleaving can be reproduced). Replaying the same seed against
+//! the same test body pops the same sequence of indices, and therefore
+//! drives the same interleaving, every time.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+
+use crate::runtime::park::Parker;
+
+/// A small, fast, non-cryptographic PRNG (xorshift64*), used only to decide
+/// scheduling order. Not suitable for anything security sensitive.
+#[derive(Debug, Clone)]
+pub(crate) struct Xorshift {
+    state: u64,
+}
+
+impl Xorshift {
+    pub(crate) fn new(seed: u64) -> Self {
+        // Zero is a fixed point of xorshift; perturb it so `seed = 0` still
+        // produces a useful sequence.
+        Self {
+            state: seed ^ 0x9E3779B97F4A7C15,
+        }
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x.wrapping_mul(0x2545F4914F6CDD1D)
+    }
+
+    /// Returns a pseudo-random index in `0..len`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is 0.
+    pub(crate) fn next_index(&mut self, len: usize) -> usize {
+        assert!(len > 0, "cannot pick an index into an empty queue");
+        (self.next_u64() % len as u64) as usize
+    }
+}
+
+type BoxedFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+struct Task {
+    future: Mutex<Option<BoxedFuture>>,
+    // Set while the task is sitting in `ready`, so a `wake` that races with
+    // the task already being polled off the queue doesn't enqueue it twice.
+    queued: AtomicBool,
+    ready: Arc<Mutex<Vec<Arc<Task>>>>,
+    parker: Arc<Parker>,
+}
+
+impl Wake for Task {
+    fn wake(self: Arc<Self>) {
+        Task::wake_by_ref(&self)
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        if !self.queued.swap(true, Ordering::AcqRel) {
+            self.ready.lock().unwrap().push(Arc::clone(self));
+            self.parker.unpark();
+        }
+    }
+}
+
+/// A single-threaded executor whose ready queue is popped in a seeded
+/// pseudo-random order rather than FIFO, so running the same test body
+/// against the same seed reproduces the same task interleaving.
+pub(crate) struct DeterministicScheduler {
+    rng: Xorshift,
+}
+
+impl DeterministicScheduler {
+    pub(crate) fn new(seed: u64) -> Self {
+        Self {
+            rng: Xorshift::new(seed),
+        }
+    }
+
+    /// Runs `future` to completion, polling it (and any task it wakes)
+    /// through the random-order ready queue described above.
+    pub(crate) fn block_on<F: Future + Send + 'static>(&mut self, future: F) -> F::Output
+    where
+        F::Output: Send + 'static,
+    {
+        let parker = Arc::new(Parker::new());
+        let ready = Arc::new(Mutex::new(Vec::new()));
+        let output = Arc::new(Mutex::new(None));
+
+        let root = {
+            let output = Arc::clone(&output);
+            let ready = Arc::clone(&ready);
+            let parker = Arc::clone(&parker);
+            let future: BoxedFuture = Box::pin(async move {
+                let result = future.await;
+                *output.lock().unwrap() = Some(result);
+            });
+            Arc::new(Task {
+                future: Mutex::new(Some(future)),
+                queued: AtomicBool::new(true),
+                ready,
+                parker,
+            })
+        };
+        ready.lock().unwrap().push(Arc::clone(&root));
+
+        loop {
+            if let Some(output) = output.lock().unwrap().take() {
+                return output;
+            }
+
+            let next = {
+                let mut ready = ready.lock().unwrap();
+                if ready.is_empty() {
+                    None
+                } else {
+                    let index = self.rng.next_index(ready.len());
+                    Some(ready.swap_remove(index))
+                }
+            };
+
+            let Some(task) = next else {
+                parker.park();
+                continue;
+            };
+
+            task.queued.store(false, Ordering::Release);
+            let waker = Waker::from(Arc::clone(&task));
+            let mut cx = Context::from_waker(&waker);
+            let mut slot = task.future.lock().unwrap();
+            if let Some(future) = slot.as_mut() {
+                if future.as_mut().poll(&mut cx).is_ready() {
+                    *slot = None;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn xorshift_is_reproducible_from_the_same_seed() {
+        // `DeterministicScheduler`'s whole value proposition rests on this:
+        // the sequence of indices it pops its ready queue with is entirely a
+        // function of the seed.
+        let mut a = Xorshift::new(0x1234_5678);
+        let mut b = Xorshift::new(0x1234_5678);
+        let from_a: Vec<u64> = (0..8).map(|_| a.next_u64()).collect();
+        let from_b: Vec<u64> = (0..8).map(|_| b.next_u64()).collect();
+        assert_eq!(from_a, from_b);
+    }
+
+    #[test]
+    fn xorshift_diverges_across_seeds() {
+        let mut a = Xorshift::new(1);
+        let mut b = Xorshift::new(2);
+        let from_a: Vec<u64> = (0..8).map(|_| a.next_u64()).collect();
+        let from_b: Vec<u64> = (0..8).map(|_| b.next_u64()).collect();
+        assert_ne!(from_a, from_b);
+    }
+
+    #[test]
+    fn block_on_runs_the_future_to_completion() {
+        let output = DeterministicScheduler::new(42).block_on(async { 1 + 1 });
+        assert_eq!(output, 2);
+    }
+
+    #[test]
+    fn same_seed_reproduces_the_same_run() {
+        fn run(seed: u64) -> Vec<u32> {
+            DeterministicScheduler::new(seed).block_on(async {
+                let mut seen = Vec::new();
+                for i in 0..4u32 {
+                    // Yield once so the task re-enters the ready queue and
+                    // is popped again via `Xorshift::next_index`, instead of
+                    // running to completion in a single poll.
+                    YieldOnce::default().await;
+                    seen.push(i);
+                }
+                seen
+            })
+        }
+
+        assert_eq!(run(0xC0FFEE), run(0xC0FFEE));
+    }
+
+    #[derive(Default)]
+    struct YieldOnce {
+        yielded: bool,
+    }
+
+    impl Future for YieldOnce {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if self.yielded {
+                Poll::Ready(())
+            } else {
+                self.yielded = true;
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+}
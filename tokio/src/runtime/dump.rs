@@ -0,0 +1,44 @@
+//! [`Handle::dump`](super::Handle::dump)'s output type: a snapshot of
+//! in-flight task backtraces.
+
+use crate::runtime::task::trace;
+
+/// A snapshot of task backtraces captured by [`Handle::dump`](super::Handle::dump).
+#[derive(Debug)]
+pub struct Dump {
+    tasks: Vec<Task>,
+}
+
+impl Dump {
+    pub(crate) fn capture() -> Self {
+        let frames = trace::take_frames();
+        let tasks = if frames.is_empty() { Vec::new() } else { vec![Task { frames }] };
+        Self { tasks }
+    }
+
+    /// Returns the backtrace of every task this dump captured.
+    pub fn tasks(&self) -> impl Iterator<Item = &Task> {
+        self.tasks.iter()
+    }
+}
+
+/// One task's captured backtrace.
+#[derive(Debug)]
+pub struct Task {
+    frames: Vec<usize>,
+}
+
+impl Task {
+    /// Returns the captured frames as raw instruction pointers, innermost
+    /// (most deeply nested) frame first, in the order
+    /// [`trace_leaf`](crate::runtime::task::trace::trace_leaf) recorded
+    /// them.
+    ///
+    /// These are left unsymbolicated: resolving instruction pointers into
+    /// function names and source locations (e.g. via the `backtrace`
+    /// crate) is far more expensive than capturing them, and a `Dump` may
+    /// end up being discarded without ever being rendered.
+    pub fn frame_ips(&self) -> &[usize] {
+        &self.frames
+    }
+}
@@ -0,0 +1,24 @@
+use crate::runtime::dump::Dump;
+
+/// A handle to a [`Runtime`](crate::runtime::Runtime), currently only used
+/// to [`dump`](Handle::dump) in-flight task backtraces.
+#[derive(Debug, Clone)]
+pub struct Handle {}
+
+impl Handle {
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+
+    /// Captures a snapshot of in-flight task backtraces.
+    ///
+    /// This runtime has no task registry, so unlike upstream Tokio's
+    /// `Handle::dump`, this only takes the frames that leaf futures polled
+    /// on the *calling* thread have recorded since the previous dump on that
+    /// thread, as a single [`Task`](crate::runtime::Task). It cannot see
+    /// tasks running on other threads, and returns an empty [`Dump`] if no
+    /// frames were recorded.
+    pub fn dump(&self) -> Dump {
+        Dump::capture()
+    }
+}
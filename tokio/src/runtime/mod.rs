@@ -0,0 +1,35 @@
+//! The Tokio runtime.
+//!
+//! Unlike other Rust programs, asynchronous applications require runtime
+//! support. In particular, the following runtime services are necessary:
+//!
+//! * An **I/O event loop**, called the driver, which drives I/O resources and
+//!   dispatches I/O events to tasks that depend on them.
+//! * A **scheduler** to execute [tasks] that use these I/O resources.
+//! * A **timer** for scheduling work to run after a set period of time.
+//!
+//! [tasks]: crate::task
+
+pub(crate) mod task;
+
+pub(crate) mod park;
+
+mod builder;
+pub use builder::Builder;
+
+mod runtime;
+pub use runtime::Runtime;
+
+pub(crate) mod compute_pool;
+
+cfg_test_util! {
+    pub(crate) mod deterministic;
+}
+
+cfg_taskdump! {
+    mod dump;
+    pub use dump::{Dump, Task};
+
+    mod handle;
+    pub use handle::Handle;
+}
@@ -0,0 +1,75 @@
+//! The thread-parking primitive shared by every `block_on`-style executor in
+//! this crate (the full runtime, `rt_minimal`, and the deterministic test
+//! scheduler): park the current thread when there's no ready work, and
+//! unpark it from a `Waker` once there is.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::task::{RawWaker, RawWakerVTable, Waker};
+
+/// Blocks the current thread until [`unpark`](Parker::unpark) is called,
+/// unless it already was since the last park (so a wake that races ahead of
+/// the corresponding park isn't lost).
+pub(crate) struct Parker {
+    unparked: AtomicBool,
+    mutex: Mutex<()>,
+    condvar: Condvar,
+}
+
+impl Parker {
+    pub(crate) fn new() -> Self {
+        Self {
+            unparked: AtomicBool::new(false),
+            mutex: Mutex::new(()),
+            condvar: Condvar::new(),
+        }
+    }
+
+    pub(crate) fn park(&self) {
+        let guard = self.mutex.lock().unwrap();
+        if self.unparked.swap(false, Ordering::SeqCst) {
+            return;
+        }
+        let _guard = self
+            .condvar
+            .wait_while(guard, |_| !self.unparked.load(Ordering::SeqCst))
+            .unwrap();
+        self.unparked.store(false, Ordering::SeqCst);
+    }
+
+    pub(crate) fn unpark(&self) {
+        let _guard = self.mutex.lock().unwrap();
+        self.unparked.store(true, Ordering::SeqCst);
+        self.condvar.notify_one();
+    }
+}
+
+/// Builds a [`Waker`] that, when woken, unparks `parker`.
+pub(crate) fn waker_from_parker(parker: Arc<Parker>) -> Waker {
+    fn clone(ptr: *const ()) -> RawWaker {
+        let parker = unsafe { Arc::from_raw(ptr as *const Parker) };
+        std::mem::forget(parker.clone());
+        std::mem::forget(parker);
+        RawWaker::new(ptr, &VTABLE)
+    }
+
+    fn wake(ptr: *const ()) {
+        let parker = unsafe { Arc::from_raw(ptr as *const Parker) };
+        parker.unpark();
+    }
+
+    fn wake_by_ref(ptr: *const ()) {
+        let parker = unsafe { Arc::from_raw(ptr as *const Parker) };
+        parker.unpark();
+        std::mem::forget(parker);
+    }
+
+    fn drop(ptr: *const ()) {
+        unsafe { Arc::from_raw(ptr as *const Parker) };
+    }
+
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, wake, wake_by_ref, drop);
+
+    let ptr = Arc::into_raw(parker) as *const ();
+    unsafe { Waker::from_raw(RawWaker::new(ptr, &VTABLE)) }
+}
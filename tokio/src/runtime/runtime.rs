@@ -0,0 +1,82 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use crate::runtime::park::{waker_from_parker, Parker};
+
+/// The Tokio runtime.
+///
+/// Built via [`Builder`](crate::runtime::Builder). The compute pool is
+/// process-wide and is not shut down when a `Runtime` is dropped.
+#[derive(Debug)]
+pub struct Runtime {
+    start_paused: bool,
+    deterministic_seed: Option<u64>,
+}
+
+impl Runtime {
+    pub(crate) fn new(start_paused: bool, deterministic_seed: Option<u64>) -> Self {
+        Self {
+            start_paused,
+            deterministic_seed,
+        }
+    }
+
+    /// Runs `future` to completion on the current thread, parking it
+    /// whenever the future isn't making progress.
+    ///
+    /// If this runtime was built with
+    /// [`Builder::deterministic_seed`](crate::runtime::Builder::deterministic_seed),
+    /// `future` is instead driven by a
+    /// [`DeterministicScheduler`](crate::runtime::deterministic::DeterministicScheduler),
+    /// which pops its ready queue in a seeded pseudo-random order rather
+    /// than FIFO. That scheduler wakes tasks through [`std::task::Wake`],
+    /// whose blanket `Waker` impl requires `Send + Sync`, so `F` must be
+    /// `Send + 'static` here even on the (otherwise not-necessarily-`Send`)
+    /// default path.
+    pub fn block_on<F>(&self, future: F) -> F::Output
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        // `start_paused` only affects `tokio::time`'s auto-advance behavior,
+        // which this runtime doesn't drive yet; accepted here so callers
+        // that built with it don't need special-casing later.
+        let _ = self.start_paused;
+
+        cfg_test_util! {
+            if let Some(seed) = self.deterministic_seed {
+                return crate::runtime::deterministic::DeterministicScheduler::new(seed).block_on(future);
+            }
+        }
+
+        self.block_on_default(future)
+    }
+
+    cfg_taskdump! {
+        /// Returns a [`Handle`](crate::runtime::Handle) to this runtime, for
+        /// capturing in-flight task backtraces via
+        /// [`Handle::dump`](crate::runtime::Handle::dump).
+        pub fn handle(&self) -> crate::runtime::Handle {
+            crate::runtime::Handle::new()
+        }
+    }
+
+    fn block_on_default<F: Future>(&self, mut future: F) -> F::Output {
+        let parker = Arc::new(Parker::new());
+        let waker = waker_from_parker(parker.clone());
+        let mut cx = Context::from_waker(&waker);
+
+        // Safety: `future` is not moved again after being pinned here; it is
+        // a local that lives until this function returns.
+        let mut future = unsafe { Pin::new_unchecked(&mut future) };
+
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => parker.park(),
+            }
+        }
+    }
+}
@@ -0,0 +1,8 @@
+//! The task module.
+//!
+//! The task module contains the code that manages spawned tasks and provides
+//! a safe API for the rest of the runtime to use.
+
+cfg_taskdump! {
+    pub(crate) mod trace;
+}
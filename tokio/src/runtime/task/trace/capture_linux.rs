@@ -0,0 +1,88 @@
+//! Linux stack capture, via frame-pointer based unwinding.
+//!
+//! Unlike the macOS and Windows backends, which lean on the `backtrace`
+//! crate's unwinder, a Linux build (compiled with frame pointers retained)
+//! is walked directly: `rbp`/`x29` roots a linked list of saved
+//! frame-pointer/return-address pairs, one per call frame, terminated by a
+//! null frame pointer.
+
+use std::task::{Context, Poll};
+
+/// Safety net against a corrupt or cyclic frame-pointer chain; real call
+/// stacks are nowhere near this deep.
+const MAX_FRAMES: usize = 128;
+
+/// Called from within a leaf future's `poll` to append the current stack to
+/// the calling thread's frame buffer. Every call records; nothing checks
+/// whether a dump has been requested.
+pub(crate) fn trace_leaf(_cx: &mut Context<'_>) -> Poll<()> {
+    // SAFETY: `walk` only follows the frame-pointer chain as far as it
+    // still looks like a valid linked list (non-null, word-aligned,
+    // strictly increasing), bailing out well before `MAX_FRAMES` if the
+    // chain is corrupt or the binary was built without frame pointers.
+    unsafe {
+        walk(frame_pointer());
+    }
+    Poll::Ready(())
+}
+
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+fn frame_pointer() -> *const usize {
+    let fp: *const usize;
+    unsafe {
+        std::arch::asm!("mov {}, rbp", out(reg) fp);
+    }
+    fp
+}
+
+#[cfg(target_arch = "x86")]
+#[inline(always)]
+fn frame_pointer() -> *const usize {
+    let fp: *const usize;
+    unsafe {
+        std::arch::asm!("mov {}, ebp", out(reg) fp);
+    }
+    fp
+}
+
+#[cfg(target_arch = "aarch64")]
+#[inline(always)]
+fn frame_pointer() -> *const usize {
+    let fp: *const usize;
+    unsafe {
+        std::arch::asm!("mov {}, x29", out(reg) fp);
+    }
+    fp
+}
+
+/// Walks a standard `[saved_fp, return_address]` frame-pointer chain
+/// starting at `fp`, recording each return address via
+/// [`super::record_frame`].
+///
+/// # Safety
+///
+/// `fp` must be either null or point at a valid two-word frame record (the
+/// caller's saved frame pointer followed by a return address), as produced
+/// by an `rbp`/`x29`-based prologue.
+unsafe fn walk(mut fp: *const usize) {
+    for _ in 0..MAX_FRAMES {
+        if fp.is_null() || (fp as usize) % std::mem::align_of::<usize>() != 0 {
+            break;
+        }
+
+        let next_fp = *fp;
+        let return_address = *fp.add(1);
+        if return_address == 0 {
+            break;
+        }
+        super::record_frame(return_address as *mut std::ffi::c_void);
+
+        // Frame pointers must move up the stack; a non-increasing chain
+        // means we've hit the bottom or the chain is corrupt.
+        if next_fp <= fp as usize {
+            break;
+        }
+        fp = next_fp as *const usize;
+    }
+}
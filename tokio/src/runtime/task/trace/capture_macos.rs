@@ -0,0 +1,20 @@
+//! macOS stack capture.
+//!
+//! The Linux backend walks frame pointers directly, but macOS's system
+//! allocator and Objective-C runtime frames don't reliably preserve them, so
+//! this backend instead resolves frames via the `backtrace` crate, which
+//! already knows how to unwind macOS's ABI on both `aarch64` and `x86_64`.
+
+use std::task::{Context, Poll};
+
+/// Called from within a leaf future's `poll` to append the current stack to
+/// the calling thread's frame buffer. Every call records; nothing checks
+/// whether a dump has been requested.
+pub(crate) fn trace_leaf(_cx: &mut Context<'_>) -> Poll<()> {
+    backtrace::trace(|frame| {
+        super::record_frame(frame.ip());
+        true
+    });
+
+    Poll::Ready(())
+}
@@ -0,0 +1,19 @@
+//! Windows stack capture.
+//!
+//! Walks the current call stack using the OS unwinder
+//! (`RtlVirtualUnwind` via `StackWalkEx`, through the `backtrace` crate),
+//! mirroring the macOS backend.
+
+use std::task::{Context, Poll};
+
+/// Called from within a leaf future's `poll` to append the current stack to
+/// the calling thread's frame buffer. Every call records; nothing checks
+/// whether a dump has been requested.
+pub(crate) fn trace_leaf(_cx: &mut Context<'_>) -> Poll<()> {
+    backtrace::trace(|frame| {
+        super::record_frame(frame.ip());
+        true
+    });
+
+    Poll::Ready(())
+}
@@ -0,0 +1,43 @@
+//! Machinery for capturing async stack traces, used by
+//! [`Handle::dump`](crate::runtime::Handle::dump).
+//!
+//! Capturing a task's backtrace requires walking the leaf future's stack at
+//! the point where it yields inside [`trace_leaf`]. How that walk happens is
+//! platform-specific, so the capture logic lives in a per-target-os module
+//! selected below.
+
+#[cfg(all(
+    target_os = "linux",
+    any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64")
+))]
+#[path = "capture_linux.rs"]
+mod capture;
+
+#[cfg(all(target_os = "macos", any(target_arch = "aarch64", target_arch = "x86_64")))]
+#[path = "capture_macos.rs"]
+mod capture;
+
+#[cfg(all(target_os = "windows", target_arch = "x86_64"))]
+#[path = "capture_windows.rs"]
+mod capture;
+
+pub(crate) use capture::trace_leaf;
+
+std::thread_local! {
+    /// Raw instruction pointers collected by the current thread's in-flight
+    /// trace, most-recently-captured frame last. They are never symbolicated
+    /// here; a [`Dump`](crate::runtime::Dump) exposes them as raw addresses.
+    static FRAMES: std::cell::RefCell<Vec<usize>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+/// Appends a raw frame to the current thread's in-flight trace. Called by
+/// each platform's [`trace_leaf`] as it walks the stack.
+fn record_frame(ip: *mut std::ffi::c_void) {
+    FRAMES.with(|frames| frames.borrow_mut().push(ip as usize));
+}
+
+/// Takes (and clears) the raw frames the current thread has collected so
+/// far, oldest first. Called by [`Dump::capture`](crate::runtime::dump::Dump::capture).
+pub(crate) fn take_frames() -> Vec<usize> {
+    FRAMES.with(|frames| std::mem::take(&mut *frames.borrow_mut()))
+}
@@ -0,0 +1,43 @@
+use crate::runtime::compute_pool;
+use crate::task::JoinHandle;
+
+/// Runs the provided closure on the compute pool, returning a future
+/// representing the result.
+///
+/// The compute pool is a dedicated thread pool, separate from both the core
+/// (async) threads and the blocking thread pool. It is bounded by the number
+/// of available CPUs (by default), and is intended for parallel, CPU-bound
+/// work rather than blocking I/O — use
+/// [`spawn_blocking`](crate::task::spawn_blocking) for the latter.
+///
+/// The closure is allowed to run for an extended period of time without
+/// yielding: unlike tasks run via [`spawn`](crate::task::spawn), compute-pool
+/// closures are not expected to ever call `.await`. If the closure panics,
+/// the panic is caught and propagated through the returned [`JoinHandle`]
+/// when it is awaited, identically to [`spawn_blocking`](crate::task::spawn_blocking).
+///
+/// The pool is configured by the first [`Builder`](crate::runtime::Builder)
+/// to [`build`](crate::runtime::Builder::build) one (see
+/// [`Builder::max_compute_threads`](crate::runtime::Builder::max_compute_threads)
+/// and friends); calling this before any runtime has been built lazily
+/// starts a pool with the default configuration.
+///
+/// # Examples
+///
+/// ```
+/// # async fn dox() {
+/// let result = tokio::task::spawn_compute(|| {
+///     // Some CPU-bound work.
+///     (1..=100u64).product::<u64>()
+/// }).await.unwrap();
+/// # let _ = result;
+/// # }
+/// ```
+#[track_caller]
+pub fn spawn_compute<F, R>(f: F) -> JoinHandle<R>
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    compute_pool::global().spawn(f)
+}
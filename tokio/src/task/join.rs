@@ -0,0 +1,121 @@
+use std::any::Any;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+/// An owned permission to join on (await the result of) a spawned task.
+///
+/// Currently produced by [`spawn_compute`](crate::task::spawn_compute());
+/// other spawn entry points will return the same type.
+pub struct JoinHandle<T> {
+    shared: Arc<Shared<T>>,
+}
+
+pub(crate) struct Shared<T> {
+    state: Mutex<State<T>>,
+}
+
+enum State<T> {
+    Pending(Option<Waker>),
+    Ready(std::thread::Result<T>),
+    Taken,
+}
+
+impl<T> Shared<T> {
+    pub(crate) fn new() -> Arc<Self> {
+        Arc::new(Self {
+            state: Mutex::new(State::Pending(None)),
+        })
+    }
+
+    /// Stores the task's outcome and wakes whoever is polling the
+    /// `JoinHandle`, if anyone is.
+    pub(crate) fn complete(&self, result: std::thread::Result<T>) {
+        let waker = {
+            let mut state = self.state.lock().unwrap();
+            match std::mem::replace(&mut *state, State::Ready(result)) {
+                State::Pending(waker) => waker,
+                // Only reached if `complete` is called a second time; the
+                // compute pool calls it once per job. There is no waker here.
+                State::Ready(_) | State::Taken => None,
+            }
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> JoinHandle<T> {
+    pub(crate) fn new(shared: Arc<Shared<T>>) -> Self {
+        Self { shared }
+    }
+}
+
+impl<T> Future for JoinHandle<T> {
+    type Output = Result<T, JoinError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.shared.state.lock().unwrap();
+        match &mut *state {
+            State::Pending(waker_slot) => {
+                *waker_slot = Some(cx.waker().clone());
+                Poll::Pending
+            }
+            State::Ready(_) => {
+                let State::Ready(result) = std::mem::replace(&mut *state, State::Taken) else {
+                    unreachable!("just matched State::Ready above")
+                };
+                Poll::Ready(result.map_err(JoinError::from_panic))
+            }
+            State::Taken => panic!("`JoinHandle` polled after it already completed"),
+        }
+    }
+}
+
+impl<T> fmt::Debug for JoinHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JoinHandle").finish_non_exhaustive()
+    }
+}
+
+/// Task failed to execute to completion, because it panicked.
+pub struct JoinError {
+    panic: Box<dyn Any + Send + 'static>,
+}
+
+impl JoinError {
+    fn from_panic(payload: Box<dyn Any + Send + 'static>) -> Self {
+        Self { panic: payload }
+    }
+
+    /// Returns `true` if the error was caused by the task panicking.
+    ///
+    /// This is always `true`, since (unlike `spawn`'s `JoinError`) a
+    /// compute-pool task cannot be cancelled.
+    pub fn is_panic(&self) -> bool {
+        true
+    }
+
+    /// Consumes the `JoinError`, returning the panic payload so it can be
+    /// resumed with [`std::panic::resume_unwind`].
+    pub fn into_panic(self) -> Box<dyn Any + Send + 'static> {
+        self.panic
+    }
+}
+
+impl fmt::Display for JoinError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "task panicked")
+    }
+}
+
+impl fmt::Debug for JoinError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JoinError").field("is_panic", &true).finish()
+    }
+}
+
+impl std::error::Error for JoinError {}
@@ -0,0 +1,28 @@
+//! Asynchronous green-threads.
+//!
+//! ## What are Tasks?
+//!
+//! A _task_ is a light weight, non-blocking unit of execution. It is similar
+//! to an OS thread, but rather than being managed by the OS scheduler, they
+//! are managed by the [Tokio runtime][rt]. Another name for this general
+//! pattern is [green threads]. If you are familiar with [Goroutines] from Go,
+//! then you can think of Tokio's tasks as something similar.
+//!
+//! [rt]: crate::runtime::Runtime
+//! [green threads]: https://en.wikipedia.org/wiki/Green_threads
+//! [Goroutines]: https://tour.golang.org/concurrency/1
+//!
+//! ## CPU-bound and blocking code
+//!
+//! In addition to `spawn`, for asynchronous tasks, and `spawn_blocking`, for
+//! blocking I/O, this module provides [`spawn_compute`] for dispatching
+//! CPU-bound closures onto a dedicated compute pool. See its
+//! documentation for details.
+
+cfg_rt! {
+    pub(crate) mod join;
+    pub use join::{JoinError, JoinHandle};
+
+    mod compute;
+    pub use compute::spawn_compute;
+}
@@ -0,0 +1,263 @@
+//! An intrusive double-ended linked list of `Pin`ned nodes.
+//!
+//! Unlike `std::collections::LinkedList`, nodes are not owned by the list:
+//! the list only holds raw pointers into nodes that live elsewhere (usually
+//! pinned in a `Box`, or on the stack of a suspended `async fn`). Pushing and
+//! popping therefore never allocate, and a node can unlink itself in O(1)
+//! given nothing but a pointer to itself — the property every intrusive
+//! waiter list, channel queue, and timer-wheel slot in this crate relies on.
+
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::ptr::NonNull;
+
+/// An intrusive linked list of `L: Link` nodes.
+///
+/// `L::Target` must embed a [`Pointers<L::Target>`] field, accessed via
+/// [`Link::pointers`]; the list itself stores only the head and tail
+/// pointers, never the nodes.
+pub(crate) struct LinkedList<L, T> {
+    head: Option<NonNull<T>>,
+    tail: Option<NonNull<T>>,
+    _marker: std::marker::PhantomData<*const L>,
+}
+
+unsafe impl<L: Link, T> Send for LinkedList<L, T> where L::Target: Send {}
+unsafe impl<L: Link, T> Sync for LinkedList<L, T> where L::Target: Sync {}
+
+impl<L, T> std::fmt::Debug for LinkedList<L, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LinkedList")
+            .field("head", &self.head)
+            .field("tail", &self.tail)
+            .finish()
+    }
+}
+
+impl<L: Link> LinkedList<L, L::Target> {
+    /// Creates an empty linked list.
+    pub(crate) const fn new() -> LinkedList<L, L::Target> {
+        LinkedList {
+            head: None,
+            tail: None,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns whether the list has no nodes.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Links `val` in as the new head of the list.
+    pub(crate) fn push_front(&mut self, val: L::Handle) {
+        let ptr = L::as_raw(&val);
+        std::mem::forget(val);
+
+        // SAFETY: `ptr` was just obtained from a live handle we're about to
+        // forget ownership of, so the list becomes responsible for it.
+        unsafe {
+            Pointers::set_next(L::pointers(ptr), self.head);
+            Pointers::set_prev(L::pointers(ptr), None);
+
+            if let Some(head) = self.head {
+                Pointers::set_prev(L::pointers(head), Some(ptr));
+            }
+
+            self.head = Some(ptr);
+            if self.tail.is_none() {
+                self.tail = Some(ptr);
+            }
+        }
+    }
+
+    /// Unlinks the tail of the list and returns it, or `None` if the list is
+    /// empty.
+    pub(crate) fn pop_back(&mut self) -> Option<L::Handle> {
+        unsafe {
+            let last = self.tail?;
+            self.tail = Pointers::get_prev(L::pointers(last));
+
+            if let Some(prev) = self.tail {
+                Pointers::set_next(L::pointers(prev), None);
+            } else {
+                self.head = None;
+            }
+
+            Pointers::set_prev(L::pointers(last), None);
+            Pointers::set_next(L::pointers(last), None);
+
+            Some(L::from_raw(last))
+        }
+    }
+
+    /// Unlinks the node `node` points at from the list, returning its
+    /// handle, or `None` if it is not (or is no longer) linked into this
+    /// list.
+    ///
+    /// # Safety
+    ///
+    /// `node` must either currently be linked into this list, or already
+    /// unlinked from it; passing a pointer linked into a *different* list is
+    /// undefined behavior.
+    pub(crate) unsafe fn remove(&mut self, node: NonNull<L::Target>) -> Option<L::Handle> {
+        let prev = Pointers::get_prev(L::pointers(node));
+        let next = Pointers::get_next(L::pointers(node));
+
+        // A node not linked into any list has `prev == next == None` *and*
+        // is not the sole element (head/tail both pointing at it); guard
+        // against a double-remove by checking it's actually reachable from
+        // `self.head`/`self.tail`.
+        if prev.is_none() && next.is_none() && self.head != Some(node) {
+            return None;
+        }
+
+        if let Some(prev) = prev {
+            Pointers::set_next(L::pointers(prev), next);
+        } else {
+            self.head = next;
+        }
+
+        if let Some(next) = next {
+            Pointers::set_prev(L::pointers(next), prev);
+        } else {
+            self.tail = prev;
+        }
+
+        Pointers::set_prev(L::pointers(node), None);
+        Pointers::set_next(L::pointers(node), None);
+
+        Some(L::from_raw(node))
+    }
+}
+
+impl<L: Link> Drop for LinkedList<L, L::Target> {
+    fn drop(&mut self) {
+        while self.pop_back().is_some() {}
+    }
+}
+
+/// Trait implemented by the node type linked into a [`LinkedList`].
+///
+/// # Safety
+///
+/// Implementations must ensure `pointers` always returns a pointer to the
+/// same `Pointers<Target>` field for a given `target`, and that `as_raw`
+/// followed by `from_raw` round-trips to the original handle.
+pub(crate) unsafe trait Link {
+    /// The owned handle pushed into, and popped back out of, the list
+    /// (typically a `Pin<Box<Self::Target>>`).
+    type Handle;
+
+    /// The node type embedding a [`Pointers<Target>`] field.
+    type Target;
+
+    /// Borrows the raw pointer backing `handle`, without affecting its
+    /// ownership.
+    fn as_raw(handle: &Self::Handle) -> NonNull<Self::Target>;
+
+    /// Reconstructs the owned handle from a raw pointer previously produced
+    /// by `as_raw`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been obtained from `as_raw` on a handle whose
+    /// ownership was then given up (e.g. via `mem::forget`), and must not
+    /// have already been converted back.
+    unsafe fn from_raw(ptr: NonNull<Self::Target>) -> Self::Handle;
+
+    /// Returns the address of the `Pointers` field embedded in `target`.
+    ///
+    /// # Safety
+    ///
+    /// `target` must point at a live, properly initialized `Self::Target`.
+    unsafe fn pointers(target: NonNull<Self::Target>) -> NonNull<Pointers<Self::Target>>;
+}
+
+/// The intrusive prev/next pointers embedded in every node of a
+/// [`LinkedList`].
+pub(crate) struct Pointers<T> {
+    inner: std::cell::UnsafeCell<PointersInner<T>>,
+}
+
+struct PointersInner<T> {
+    prev: Option<NonNull<T>>,
+    next: Option<NonNull<T>>,
+    _pin: PhantomPinned,
+}
+
+impl<T> Pointers<T> {
+    /// Creates a new, unlinked set of pointers.
+    pub(crate) fn new() -> Pointers<T> {
+        Pointers {
+            inner: std::cell::UnsafeCell::new(PointersInner {
+                prev: None,
+                next: None,
+                _pin: PhantomPinned,
+            }),
+        }
+    }
+
+    unsafe fn get_prev(ptr: NonNull<Self>) -> Option<NonNull<T>> {
+        (*ptr.as_ref().inner.get()).prev
+    }
+
+    unsafe fn get_next(ptr: NonNull<Self>) -> Option<NonNull<T>> {
+        (*ptr.as_ref().inner.get()).next
+    }
+
+    unsafe fn set_prev(ptr: NonNull<Self>, prev: Option<NonNull<T>>) {
+        (*ptr.as_ref().inner.get()).prev = prev;
+    }
+
+    unsafe fn set_next(ptr: NonNull<Self>, next: Option<NonNull<T>>) {
+        (*ptr.as_ref().inner.get()).next = next;
+    }
+}
+
+impl<T> Default for Pointers<T> {
+    fn default() -> Self {
+        Pointers::new()
+    }
+}
+
+impl<T> std::fmt::Debug for Pointers<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        unsafe {
+            let inner = &*self.inner.get();
+            f.debug_struct("Pointers")
+                .field("prev", &inner.prev)
+                .field("next", &inner.next)
+                .finish()
+        }
+    }
+}
+
+// SAFETY: the pointers are only ever dereferenced while holding whatever
+// synchronization protects the `LinkedList` they're linked into; `Pointers`
+// itself just stores addresses.
+unsafe impl<T: Send> Send for Pointers<T> {}
+unsafe impl<T: Sync> Sync for Pointers<T> {}
+
+/// Generates `addr_of_<field>` helpers that compute the address of a
+/// `Pointers<Self>` field from a `NonNull<Self>` without going through a
+/// reference (so the field can be read even while the node it's embedded in
+/// is only reachable by raw pointer, as is the case for every node that's
+/// currently linked into a list).
+macro_rules! generate_addr_of_methods {
+    (impl$(<$($gen:ident),*>)? $type:ty {
+        $(unsafe fn $fn_name:ident(self: $selfty:ty) -> $retty:ty {
+            &self.$field_name:ident
+        })*
+    }) => {
+        impl$(<$($gen),*>)? $type {
+            $(
+                unsafe fn $fn_name(me: $selfty) -> $retty {
+                    let me = me.as_ptr();
+                    let field = std::ptr::addr_of_mut!((*me).$field_name);
+                    std::ptr::NonNull::new_unchecked(field)
+                }
+            )*
+        }
+    };
+}
@@ -0,0 +1,5 @@
+//! Utilities shared across the crate that don't belong to any one
+//! subsystem.
+
+#[macro_use]
+pub(crate) mod linked_list;